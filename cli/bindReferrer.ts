@@ -0,0 +1,71 @@
+import { PublicKey, Transaction, SystemProgram } from "@solana/web3.js";
+import dotenv from "dotenv";
+import { provider, wallet, program } from "./helper";
+
+dotenv.config();
+
+// Function to bind a referrer to the wallet's user data (once per user)
+const bindReferrer = async (referrer: PublicKey): Promise<string> => {
+  try {
+    console.log(`Binding referrer ${referrer.toString()}...`);
+
+    const bindReferrerIx = await program.methods
+      .bindReferrer(referrer)
+      .accounts({
+        user: wallet.publicKey,
+        userData: PublicKey.findProgramAddressSync(
+          [Buffer.from("user_data"), wallet.publicKey.toBuffer()],
+          program.programId
+        )[0],
+        referrerStats: PublicKey.findProgramAddressSync(
+          [Buffer.from("referrer"), referrer.toBuffer()],
+          program.programId
+        )[0],
+        userReferrerStats: PublicKey.findProgramAddressSync(
+          [Buffer.from("referrer"), wallet.publicKey.toBuffer()],
+          program.programId
+        )[0],
+        systemProgram: SystemProgram.programId,
+      } as any)
+      .instruction();
+
+    const transaction = new Transaction().add(bindReferrerIx);
+
+    console.log("Sending transaction...");
+    const signature = await provider.sendAndConfirm(transaction, []);
+    console.log(`Referrer bound! Signature: ${signature}`);
+    return signature;
+  } catch (error) {
+    console.error("Error binding referrer:", error);
+    throw error;
+  }
+};
+
+// Main function to parse command line arguments and execute the appropriate function
+async function main() {
+  const args = process.argv.slice(2);
+
+  if (args.length < 1) {
+    console.log("Usage:");
+    console.log("  npx ts-node cli/bindReferrer.ts <referrer_pubkey>");
+    process.exit(1);
+  }
+
+  let referrer: PublicKey;
+  try {
+    referrer = new PublicKey(args[0]);
+  } catch {
+    console.error("Invalid referrer. Please provide a base58 public key.");
+    process.exit(1);
+  }
+
+  await bindReferrer(referrer);
+}
+
+// Run the main function if this file is executed directly
+if (require.main === module) {
+  main().catch(console.error);
+}
+
+// Export the function for use in other files
+export { bindReferrer };
@@ -0,0 +1,37 @@
+import { Transaction } from "@solana/web3.js";
+import dotenv from "dotenv";
+import { provider, wallet, program } from "./helper";
+
+dotenv.config();
+
+// Function to cancel the wallet's pending withdrawal ticket
+const cancelWithdrawal = async (): Promise<string> => {
+  try {
+    console.log("Cancelling pending withdrawal...");
+
+    const cancelWithdrawalIx = await program.methods
+      .cancelWithdrawal()
+      .accounts({
+        user: wallet.publicKey,
+      } as any)
+      .instruction();
+
+    const transaction = new Transaction().add(cancelWithdrawalIx);
+
+    console.log("Sending transaction...");
+    const signature = await provider.sendAndConfirm(transaction, []);
+    console.log(`Withdrawal cancelled! Signature: ${signature}`);
+    return signature;
+  } catch (error) {
+    console.error("Error cancelling withdrawal:", error);
+    throw error;
+  }
+};
+
+// Run the main function if this file is executed directly
+if (require.main === module) {
+  cancelWithdrawal().catch(console.error);
+}
+
+// Export the function for use in other files
+export { cancelWithdrawal };
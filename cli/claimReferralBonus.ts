@@ -0,0 +1,50 @@
+import { Transaction, SystemProgram } from "@solana/web3.js";
+import {
+  ASSOCIATED_TOKEN_PROGRAM_ID,
+  getAssociatedTokenAddressSync,
+  TOKEN_PROGRAM_ID,
+} from "@solana/spl-token";
+import dotenv from "dotenv";
+import { provider, wallet, program, rewardTokenMint } from "./helper";
+
+dotenv.config();
+
+// Function to mint the referral bonus credited while the wallet had no CHIP account
+const claimReferralBonus = async (): Promise<string> => {
+  try {
+    console.log("Claiming pending referral bonus...");
+
+    const claimReferralBonusIx = await program.methods
+      .claimReferralBonus()
+      .accounts({
+        referrer: wallet.publicKey,
+        rewardMint: rewardTokenMint,
+        referrerRewardTokenAccount: getAssociatedTokenAddressSync(
+          rewardTokenMint,
+          wallet.publicKey
+        ),
+        rewardMintProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      } as any)
+      .instruction();
+
+    const transaction = new Transaction().add(claimReferralBonusIx);
+
+    console.log("Sending transaction...");
+    const signature = await provider.sendAndConfirm(transaction, []);
+    console.log(`Referral bonus claimed! Signature: ${signature}`);
+    return signature;
+  } catch (error) {
+    console.error("Error claiming referral bonus:", error);
+    throw error;
+  }
+};
+
+// Run the main function if this file is executed directly
+if (require.main === module) {
+  claimReferralBonus().catch(console.error);
+}
+
+// Export the function for use in other files
+export { claimReferralBonus };
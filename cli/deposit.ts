@@ -11,11 +11,13 @@ import {
   vaultPDA,
   getAssociatedTokenAddressWithInstruction,
   rewardTokenMint,
+  getReferrerAccounts,
 } from "./helper";
 import {
   TOKEN_PROGRAM_ID,
   NATIVE_MINT,
   getAssociatedTokenAddress,
+  getAssociatedTokenAddressSync,
   createAssociatedTokenAccountInstruction,
   createSyncNativeInstruction,
 } from "@solana/spl-token";
@@ -35,6 +37,17 @@ import { BN } from "bn.js";
 
 const API_ENDPOINT = "https://quote-api.jup.ag/v6";
 
+// Must match USER_DATA_SPACE in initialize_user_data.rs
+const USER_DATA_SPACE = 8 + 32 + 8 + 8 + 8 + 8 + 33 + 8 + 8;
+
+// Pyth INF/USD and SOL/USD price update accounts
+const INF_PRICE_ACCOUNT = new PublicKey(
+  "Ceg5oePJv1a6RR541qKeQaTepvERA3i8SvyueX9tT8Sq"
+);
+const SOL_PRICE_ACCOUNT = new PublicKey(
+  "7UVimffxr9ow1uXYxsr4LHAcV58mLzhmwaeKvJ1pjLiE"
+);
+
 const getQuote = async (
   fromMint: PublicKey,
   toMint: PublicKey,
@@ -98,8 +111,16 @@ const swapToINF = async (
   vaultAuthority: PublicKey,
   amountSOLtoWrap: number,
   userInputTokenATA: PublicKey,
-  roundNumber: number
+  roundNumber: number,
+  minInfOut: string,
+  native: boolean = false,
+  priceUpdateLst: PublicKey | null = null,
+  beneficiary: PublicKey | null = null,
+  lockDuration: number = 0
 ) => {
+  // Owner of the credited user data and CHIP, the wallet unless depositing for someone else
+  const creditedUser = beneficiary ?? wallet.publicKey;
+
   // Get swap instruction data
   let swapInstruction = instructionDataToTransactionInstruction(swapPayload);
 
@@ -169,7 +190,7 @@ const swapToINF = async (
   );
 
   const [userDataPDA] = await PublicKey.findProgramAddress(
-    [Buffer.from("user_data"), wallet.publicKey.toBuffer()],
+    [Buffer.from("user_data"), creditedUser.toBuffer()],
     program.programId
   );
 
@@ -185,19 +206,12 @@ const swapToINF = async (
     `Vault Output Token Account: ${vaultOutputTokenAccount.toString()}`
   );
 
-  // Get user reward token account and creation instruction if needed
-  const {
-    address: userRewardTokenAccount,
-    createInstruction: createUserRewardTokenAccountIx,
-  } = await getAssociatedTokenAddressWithInstruction(
+  // User reward token account, created by the program on the first deposit
+  const userRewardTokenAccount = await getAssociatedTokenAddress(
     rewardTokenMint,
-    wallet.publicKey
+    creditedUser
   );
 
-  if (createUserRewardTokenAccountIx) {
-    ataCreationInstructions.push(createUserRewardTokenAccountIx);
-  }
-
   console.log(
     `User Reward Token Account: ${userRewardTokenAccount.toString()}`
   );
@@ -209,18 +223,89 @@ const swapToINF = async (
   console.log("User Data Account:", userDataAccount);
   console.log("Vault Data Account:", vaultDataAccount);
 
-  const initializeUserDataIx = await program.methods
-    .initializeUserData()
-    .accounts({
-      user: wallet.publicKey,
-      userData: userDataPDA,
-      systemProgram: SystemProgram.programId,
-    } as any)
-    .instruction();
+  // Referrer stats and CHIP account, null unless a referrer is bound
+  const { referrerStats, referrerRewardTokenAccount } =
+    await getReferrerAccounts(creditedUser);
 
   // Create the swap instruction with corrected account naming
-  const swapIx = await program.methods
-    .depositSol(roundNumberBN, swapInstruction.data)
+  const swapIx = native
+    ? await program.methods
+        .depositNativeSol(
+          roundNumberBN,
+          new BN(amountSOLtoWrap),
+          swapInstruction.data,
+          new BN(minInfOut),
+          new BN(lockDuration)
+        )
+        .accounts({
+          user: wallet.publicKey,
+          inputMint: inputTokenMint,
+          inputMintProgram: TOKEN_PROGRAM_ID,
+          outputMint: outputTokenMint,
+          outputMintProgram: TOKEN_PROGRAM_ID,
+          // Wrapped, swapped from and closed by the program
+          tempWsolAccount: userInputTokenATA,
+          vaultTokenAccount: vaultOutputTokenAccount,
+          vaultAuthority: vaultAuthority,
+          jupiterProgram: jupiterProgramId,
+          rewardMint: rewardTokenMint,
+          userRewardTokenAccount: userRewardTokenAccount,
+          rewardMintProgram: TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+          userData: userDataPDA,
+          vaultData: vaultDataPDA,
+          round: roundPDA,
+          depositReceipt: null,
+          priceUpdateInf: INF_PRICE_ACCOUNT,
+          priceUpdateSol: SOL_PRICE_ACCOUNT,
+          referrerStats,
+          referrerRewardTokenAccount,
+        } as any)
+        .remainingAccounts(modifiedRemainingAccounts)
+        .instruction()
+    : beneficiary
+    ? await program.methods
+        .depositFor(
+          roundNumberBN,
+          swapInstruction.data,
+          new BN(minInfOut),
+          new BN(amountSOLtoWrap)
+        )
+        .accounts({
+          payer: wallet.publicKey,
+          beneficiary,
+          inputMint: inputTokenMint,
+          inputMintProgram: TOKEN_PROGRAM_ID,
+          outputMint: outputTokenMint,
+          outputMintProgram: TOKEN_PROGRAM_ID,
+          payerInputTokenAccount: userInputTokenATA,
+          vaultTokenAccount: vaultOutputTokenAccount,
+          vaultAuthority: vaultAuthority,
+          jupiterProgram: jupiterProgramId,
+          rewardMint: rewardTokenMint,
+          beneficiaryRewardTokenAccount: userRewardTokenAccount,
+          rewardMintProgram: TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+          userData: userDataPDA,
+          vaultData: vaultDataPDA,
+          round: roundPDA,
+          depositReceipt: null,
+          referrerStats,
+          referrerRewardTokenAccount,
+          priceUpdateInf: INF_PRICE_ACCOUNT,
+          priceUpdateSol: SOL_PRICE_ACCOUNT,
+          priceUpdateLst: priceUpdateLst,
+        } as any)
+        .remainingAccounts(modifiedRemainingAccounts)
+        .instruction()
+    : await program.methods
+    .depositSol(
+      roundNumberBN,
+      swapInstruction.data,
+      new BN(minInfOut),
+      new BN(amountSOLtoWrap),
+      new BN(lockDuration)
+    )
     .accounts({
       user: wallet.publicKey,
       inputMint: inputTokenMint,
@@ -242,6 +327,16 @@ const swapToINF = async (
       vaultData: vaultDataPDA,
       // Add round account
       round: roundPDA,
+      // Skip the optional per-deposit receipt
+      depositReceipt: null,
+      // Pyth feeds used to check the swap rate
+      priceUpdateInf: INF_PRICE_ACCOUNT,
+      priceUpdateSol: SOL_PRICE_ACCOUNT,
+      // Pyth feed of the input LST, only needed when not depositing WSOL
+      priceUpdateLst: priceUpdateLst,
+      // Referrer bonus accounts
+      referrerStats,
+      referrerRewardTokenAccount,
     } as any)
     .remainingAccounts(modifiedRemainingAccounts)
     .instruction();
@@ -259,9 +354,23 @@ const swapToINF = async (
   // Build the instructions array
   let instructions: TransactionInstruction[] = [];
 
-  // Add initialization instructions first if needed
-  if (!userDataAccount) {
-    instructions.push(initializeUserDataIx);
+  // User data is created by the deposit itself when missing. Only the owner can
+  // migrate it, so a beneficiary with outdated user data must run a deposit first
+  if (
+    !beneficiary &&
+    userDataAccount &&
+    userDataAccount.data.length < USER_DATA_SPACE
+  ) {
+    // Grow user data created before the current layout
+    const migrateUserDataIx = await program.methods
+      .migrateUserData()
+      .accounts({
+        user: wallet.publicKey,
+        userData: userDataPDA,
+        systemProgram: SystemProgram.programId,
+      } as any)
+      .instruction();
+    instructions.push(migrateUserDataIx);
   }
 
   console.log("ATA creation instructions:", ataCreationInstructions);
@@ -274,8 +383,7 @@ const swapToINF = async (
   instructions = [
     ...instructions,
     ...computeBudgetPayloads.map(instructionDataToTransactionInstruction),
-    wrapIx,
-    syncNativeIx,
+    ...(native || priceUpdateLst ? [] : [wrapIx, syncNativeIx]),
     swapIx,
   ];
 
@@ -318,12 +426,19 @@ async function main() {
 
   if (args.length < 2) {
     console.log("Usage:");
-    console.log("  npx ts-node cli/deposit.ts <amount_in_sol> <round_number>");
+    console.log(
+      "  npx ts-node cli/deposit.ts <amount_in_sol> <round_number> [--native]"
+    );
+    console.log(
+      "  LST_MINT=<mint> LST_PRICE_ACCOUNT=<pyth_account> npx ts-node cli/deposit.ts <amount> <round_number>"
+    );
     process.exit(1);
   }
 
   const amountInSol = parseFloat(args[0]);
   const roundNumber = parseInt(args[1]);
+  // Deposit native SOL, the program wraps it in a temporary account
+  const native = args.includes("--native");
 
   if (isNaN(amountInSol) || amountInSol <= 0) {
     console.error("Invalid SOL amount. Please provide a positive number.");
@@ -339,11 +454,39 @@ async function main() {
   const amountToWrap = Math.floor(amountInSol * LAMPORTS_PER_SOL);
 
   // Setup the swap parameters
-  const SOL = NATIVE_MINT;
+  // Accepted LSTs (jitoSOL, mSOL, bSOL, ...) are swapped from the user's LST account instead
+  const lstMint = process.env.LST_MINT ? new PublicKey(process.env.LST_MINT) : null;
+  const priceUpdateLst = process.env.LST_PRICE_ACCOUNT
+    ? new PublicKey(process.env.LST_PRICE_ACCOUNT)
+    : null;
+  // Credit the deposit to another wallet with deposit_for
+  // Lock the principal for one of the configured lock tiers, e.g. LOCK_DAYS=30
+  const lockDuration = Math.floor(parseFloat(process.env.LOCK_DAYS ?? "0") * 86400);
+  const beneficiary = process.env.BENEFICIARY
+    ? new PublicKey(process.env.BENEFICIARY)
+    : null;
+  if (beneficiary && native) {
+    console.error("Deposits for a beneficiary cannot use --native.");
+    process.exit(1);
+  }
+  if (beneficiary && lockDuration > 0) {
+    console.error("Deposits for a beneficiary cannot be locked.");
+    process.exit(1);
+  }
+  if (lstMint && (native || !priceUpdateLst)) {
+    console.error("LST deposits need LST_PRICE_ACCOUNT and cannot use --native.");
+    process.exit(1);
+  }
+  const SOL = lstMint ?? NATIVE_MINT;
   const INF = new PublicKey("EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v"); // Update this to your INF token mint
 
-  // Get user's input token account (wrapping SOL)
-  const inputTokenATA = await getAssociatedTokenAddress(SOL, wallet.publicKey);
+  // Get user's input token account (wrapping SOL), or the program's temporary WSOL account
+  const inputTokenATA = native
+    ? PublicKey.findProgramAddressSync(
+        [Buffer.from("native_sol"), wallet.publicKey.toBuffer()],
+        program.programId
+      )[0]
+    : await getAssociatedTokenAddress(SOL, wallet.publicKey);
 
   // Find vault authority PDA
   const [vaultAuthority] = await PublicKey.findProgramAddress(
@@ -365,6 +508,12 @@ async function main() {
       quoteResponse: quote,
       userPublicKey: wallet.publicKey.toBase58(),
       sourceTokenAccount: inputTokenATA.toBase58(),
+      // The program rejects routes that don't pay into the vault's INF account
+      destinationTokenAccount: getAssociatedTokenAddressSync(
+        INF,
+        vaultAuthority,
+        true
+      ).toBase58(),
       useSharedAccounts: true,
       config: {
         skipUserAccountsRpcCalls: true,
@@ -400,7 +549,13 @@ async function main() {
     vaultAuthority,
     amountToWrap,
     inputTokenATA,
-    roundNumber
+    roundNumber,
+    // Jupiter's slippage-adjusted minimum output
+    quote.otherAmountThreshold,
+    native,
+    lstMint ? priceUpdateLst : null,
+    beneficiary,
+    lockDuration
   );
 }
 
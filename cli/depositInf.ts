@@ -0,0 +1,147 @@
+import { PublicKey, Transaction, SystemProgram } from "@solana/web3.js";
+import { BN } from "@coral-xyz/anchor";
+import { getAssociatedTokenAddressSync, TOKEN_PROGRAM_ID } from "@solana/spl-token";
+import dotenv from "dotenv";
+import {
+  provider,
+  wallet,
+  program,
+  rewardTokenMint,
+  getReferrerAccounts,
+} from "./helper";
+
+dotenv.config();
+
+const INF_MINT = new PublicKey(
+  process.env.INF_MINT ?? "5oVNBeEEQvYi1cX3ir8Dx5n1P7pdxydbGF2X4TxVusJm"
+);
+const INF_DECIMALS = 9;
+
+// Pyth INF/USD and SOL/USD price update accounts
+const INF_PRICE_ACCOUNT = new PublicKey(
+  "Ceg5oePJv1a6RR541qKeQaTepvERA3i8SvyueX9tT8Sq"
+);
+const SOL_PRICE_ACCOUNT = new PublicKey(
+  "7UVimffxr9ow1uXYxsr4LHAcV58mLzhmwaeKvJ1pjLiE"
+);
+
+// Function to deposit INF held by the wallet straight into the vault
+const depositInf = async (
+  amount: number,
+  roundNumber: number,
+  lockDuration: number = 0
+): Promise<string> => {
+  try {
+    console.log(`Depositing ${amount} INF base units into round ${roundNumber}...`);
+
+    const roundNumberBN = new BN(roundNumber);
+    const [vaultAuthority] = PublicKey.findProgramAddressSync(
+      [Buffer.from("vault_authority")],
+      program.programId
+    );
+    const [roundPDA] = PublicKey.findProgramAddressSync(
+      [Buffer.from("round"), roundNumberBN.toArrayLike(Buffer, "le", 8)],
+      program.programId
+    );
+    const [userDataPDA] = PublicKey.findProgramAddressSync(
+      [Buffer.from("user_data"), wallet.publicKey.toBuffer()],
+      program.programId
+    );
+
+    // Created by the program on the first deposit, like user data
+    const userRewardTokenAccount = getAssociatedTokenAddressSync(
+      rewardTokenMint,
+      wallet.publicKey
+    );
+
+    // Referrer stats and CHIP account, null unless a referrer is bound
+    const { referrerStats, referrerRewardTokenAccount } =
+      await getReferrerAccounts(wallet.publicKey);
+
+    const depositInfIx = await program.methods
+      .depositInf(roundNumberBN, new BN(amount), new BN(lockDuration))
+      .accounts({
+        user: wallet.publicKey,
+        infMint: INF_MINT,
+        infMintProgram: TOKEN_PROGRAM_ID,
+        userInfTokenAccount: getAssociatedTokenAddressSync(
+          INF_MINT,
+          wallet.publicKey
+        ),
+        vaultTokenAccount: getAssociatedTokenAddressSync(
+          INF_MINT,
+          vaultAuthority,
+          true
+        ),
+        vaultAuthority: vaultAuthority,
+        rewardMint: rewardTokenMint,
+        userRewardTokenAccount: userRewardTokenAccount,
+        rewardMintProgram: TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+        userData: userDataPDA,
+        round: roundPDA,
+        // Skip the optional per-deposit receipt
+        depositReceipt: null,
+        priceUpdateInf: INF_PRICE_ACCOUNT,
+        priceUpdateSol: SOL_PRICE_ACCOUNT,
+        referrerStats,
+        referrerRewardTokenAccount,
+      } as any)
+      .instruction();
+
+    const transaction = new Transaction().add(depositInfIx);
+
+    console.log("Sending transaction...");
+    const signature = await provider.sendAndConfirm(transaction, []);
+    console.log(`INF deposited successfully! Signature: ${signature}`);
+    return signature;
+  } catch (error) {
+    console.error("Error depositing INF:", error);
+    throw error;
+  }
+};
+
+// Main function to parse command line arguments and execute the appropriate function
+async function main() {
+  const args = process.argv.slice(2);
+
+  if (args.length < 2) {
+    console.log("Usage:");
+    console.log("  npx ts-node cli/depositInf.ts <amount_in_inf> <round_number> [lock_days]");
+    process.exit(1);
+  }
+
+  const amountInInf = parseFloat(args[0]);
+  const roundNumber = parseInt(args[1]);
+
+  if (isNaN(amountInInf) || amountInInf <= 0) {
+    console.error("Invalid INF amount. Please provide a positive number.");
+    process.exit(1);
+  }
+
+  if (isNaN(roundNumber)) {
+    console.error("Invalid round number. Please provide a valid number.");
+    process.exit(1);
+  }
+
+  // Optional lock in days, must match one of the configured lock tiers
+  const lockDays = args[2] ? parseFloat(args[2]) : 0;
+  if (isNaN(lockDays) || lockDays < 0) {
+    console.error("Invalid lock duration. Please provide a number of days.");
+    process.exit(1);
+  }
+
+  await depositInf(
+    Math.floor(amountInInf * 10 ** INF_DECIMALS),
+    roundNumber,
+    Math.floor(lockDays * 86400)
+  );
+}
+
+// Run the main function if this file is executed directly
+if (require.main === module) {
+  main().catch(console.error);
+}
+
+// Export the function for use in other files
+export { depositInf };
@@ -215,8 +215,9 @@ const distributeZbtcReward = async (
 
     // Get Jupiter swap instructions
     console.log("Getting Jupiter swap instructions...");
+    // The vault authority PDA owns the INF and signs the swap through the program
     const jupiterSwapInstructions = await getSwapInstructions(
-      wallet.publicKey,
+      vaultAuthority,
       vaultInfTokenAccount,
       vaultZbtcTokenAccount,
       quote
@@ -274,7 +275,8 @@ const distributeZbtcReward = async (
       (account: any) => ({
         pubkey: new PublicKey(account.pubkey),
         isWritable: account.isWritable,
-        isSigner: account.isSigner,
+        // The program signs for the vault authority, nothing is signed in the transaction
+        isSigner: false,
       })
     );
 
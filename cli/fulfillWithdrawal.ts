@@ -0,0 +1,101 @@
+import { PublicKey, Transaction } from "@solana/web3.js";
+import { BN } from "@coral-xyz/anchor";
+import { getAssociatedTokenAddressSync, TOKEN_PROGRAM_ID } from "@solana/spl-token";
+import dotenv from "dotenv";
+import { provider, wallet, program } from "./helper";
+
+dotenv.config();
+
+const INF_MINT = new PublicKey("5oVNBeEEQvYi1cX3ir8Dx5n1P7pdxydbGF2X4TxVusJm");
+const INF_PRICE_ACCOUNT = new PublicKey(
+  "Ceg5oePJv1a6RR541qKeQaTepvERA3i8SvyueX9tT8Sq"
+);
+const SOL_PRICE_ACCOUNT = new PublicKey(
+  "7UVimffxr9ow1uXYxsr4LHAcV58mLzhmwaeKvJ1pjLiE"
+);
+
+// Function to pay out a user's withdrawal ticket, callable by anyone once its round closed
+const fulfillWithdrawal = async (user: PublicKey): Promise<string> => {
+  try {
+    const [ticketPDA] = PublicKey.findProgramAddressSync(
+      [Buffer.from("withdrawal_ticket"), user.toBuffer()],
+      program.programId
+    );
+    const ticket = await program.account.withdrawalTicket.fetch(ticketPDA);
+    console.log(
+      `Fulfilling withdrawal of ${ticket.solAmount.toString()} lamports for ${user.toString()} (round ${ticket.roundNumber.toString()})...`
+    );
+
+    const [vaultAuthority] = PublicKey.findProgramAddressSync(
+      [Buffer.from("vault_authority")],
+      program.programId
+    );
+    const [roundPDA] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("round"),
+        new BN(ticket.roundNumber).toArrayLike(Buffer, "le", 8),
+      ],
+      program.programId
+    );
+
+    const fulfillWithdrawalIx = await program.methods
+      .fulfillWithdrawal()
+      .accounts({
+        fulfiller: wallet.publicKey,
+        user,
+        withdrawalTicket: ticketPDA,
+        round: roundPDA,
+        tokenMint: INF_MINT,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        userTokenAccount: getAssociatedTokenAddressSync(INF_MINT, user),
+        vaultTokenAccount: getAssociatedTokenAddressSync(
+          INF_MINT,
+          vaultAuthority,
+          true
+        ),
+        vaultAuthority,
+        priceUpdateInf: INF_PRICE_ACCOUNT,
+        priceUpdateSol: SOL_PRICE_ACCOUNT,
+      } as any)
+      .instruction();
+
+    const transaction = new Transaction().add(fulfillWithdrawalIx);
+
+    console.log("Sending transaction...");
+    const signature = await provider.sendAndConfirm(transaction, []);
+    console.log(`Withdrawal fulfilled! Signature: ${signature}`);
+    return signature;
+  } catch (error) {
+    console.error("Error fulfilling withdrawal:", error);
+    throw error;
+  }
+};
+
+// Main function to parse command line arguments and execute the appropriate function
+async function main() {
+  const args = process.argv.slice(2);
+
+  if (args.length < 1) {
+    console.log("Usage:");
+    console.log("  npx ts-node cli/fulfillWithdrawal.ts <user_pubkey>");
+    process.exit(1);
+  }
+
+  let user: PublicKey;
+  try {
+    user = new PublicKey(args[0]);
+  } catch {
+    console.error("Invalid user. Please provide a base58 public key.");
+    process.exit(1);
+  }
+
+  await fulfillWithdrawal(user);
+}
+
+// Run the main function if this file is executed directly
+if (require.main === module) {
+  main().catch(console.error);
+}
+
+// Export the function for use in other files
+export { fulfillWithdrawal };
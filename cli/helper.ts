@@ -25,8 +25,10 @@ console.log(process.env.RPC_URL);
 export const programId = new PublicKey(
   "B1ph2kyNtkhscRQ3R1CAwMNM4PbGGvphHTzxR83kRsRc"
 );
+// Localnet builds swap through a stand-in program, see utils/cluster.rs
 export const jupiterProgramId = new PublicKey(
-  "JUP6LkbZbjS1jKKwapdHNy74zcZ3tLUZoi5QNyVTaV4"
+  process.env.JUPITER_PROGRAM_ID ||
+    "JUP6LkbZbjS1jKKwapdHNy74zcZ3tLUZoi5QNyVTaV4"
 );
 export const wallet = new Wallet(
   Keypair.fromSecretKey(bs58.decode(process.env.KEYPAIR))
@@ -155,3 +157,38 @@ export const getAssociatedTokenAddressWithInstruction = async (
     return { address: associatedTokenAddress, createInstruction: instruction };
   }
 };
+
+// Referrer accounts a deposit must pass once the user has bound a referrer
+export const getReferrerAccounts = async (user: PublicKey) => {
+  const [userDataPDA] = PublicKey.findProgramAddressSync(
+    [Buffer.from("user_data"), user.toBuffer()],
+    programId
+  );
+  // Missing or pre-referral user data has no referrer yet
+  const userData = await program.account.userData
+    .fetchNullable(userDataPDA)
+    .catch(() => null);
+  const referrer: PublicKey | null = userData?.referrer ?? null;
+  if (!referrer) {
+    return { referrerStats: null, referrerRewardTokenAccount: null };
+  }
+
+  // Without the referrer's CHIP account the bonus is credited for them to claim later
+  const referrerRewardTokenAccount = await getAssociatedTokenAddress(
+    rewardTokenMint,
+    referrer
+  );
+  const tokenAccountInfo = await connection.getAccountInfo(
+    referrerRewardTokenAccount
+  );
+
+  return {
+    referrerStats: PublicKey.findProgramAddressSync(
+      [Buffer.from("referrer"), referrer.toBuffer()],
+      programId
+    )[0],
+    referrerRewardTokenAccount: tokenAccountInfo
+      ? referrerRewardTokenAccount
+      : null,
+  };
+};
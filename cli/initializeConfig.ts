@@ -0,0 +1,124 @@
+import { PublicKey, Transaction, SystemProgram } from "@solana/web3.js";
+import { BN } from "@coral-xyz/anchor";
+import dotenv from "dotenv";
+import { provider, wallet, program, programId } from "./helper";
+
+dotenv.config();
+
+const BPF_LOADER_UPGRADEABLE_PROGRAM_ID = new PublicKey(
+  "BPFLoaderUpgradeab1e11111111111111111111111"
+);
+
+// Mainnet mints, overridable through the environment for localnet/devnet
+const mints = {
+  wsol: new PublicKey(
+    process.env.WSOL_MINT ?? "So11111111111111111111111111111111111111112"
+  ),
+  inf: new PublicKey(
+    process.env.INF_MINT ?? "5oVNBeEEQvYi1cX3ir8Dx5n1P7pdxydbGF2X4TxVusJm"
+  ),
+  chip: new PublicKey(
+    process.env.CHIP_MINT ?? "chip6YRCCXMy1uLbGRNErT66aYGdaVsVCQ25VA1LWNN"
+  ),
+  zbtc: new PublicKey(
+    process.env.ZBTC_MINT ?? "93a1L7xaEV7vZGt3jNMSQCgGjQx5WFYSm4CrS2s4KBcL"
+  ),
+};
+
+// Pyth INF/USD and SOL/USD feeds, 60s maximum age and 2% maximum confidence ratio
+const feedIdFromHex = (hex: string): number[] =>
+  Array.from(Buffer.from(hex.replace(/^0x/, ""), "hex"));
+
+const oracle = {
+  infUsdFeedId: feedIdFromHex(
+    process.env.INF_USD_FEED_ID ??
+      "0xf51570985c642c49c2d6e50156390fdba80bb6d5f7fa389d2f012ced4f7d208f"
+  ),
+  solUsdFeedId: feedIdFromHex(
+    process.env.SOL_USD_FEED_ID ??
+      "0xef0d8b6fda2ceba41da15d4095d1da392a0d2f8ed0c6c7bc0f4cfac8c280b56d"
+  ),
+  infMaxAge: new BN(60),
+  solMaxAge: new BN(60),
+  maxConfRatioBps: 200,
+  // Deposits fail if the swap rate is more than 1% off the oracle rate
+  maxDepositDeviationBps: 100,
+};
+
+// Function to create the config account holding the admin key
+const initializeConfig = async (
+  admin: PublicKey,
+  timelockDelay: number
+): Promise<string> => {
+  try {
+    console.log(
+      `Initializing config with admin ${admin.toString()} and timelock delay ${timelockDelay}s...`
+    );
+
+    const [configPDA] = PublicKey.findProgramAddressSync(
+      [Buffer.from("config")],
+      programId
+    );
+    const [programDataPDA] = PublicKey.findProgramAddressSync(
+      [programId.toBuffer()],
+      BPF_LOADER_UPGRADEABLE_PROGRAM_ID
+    );
+
+    console.log(`Config PDA: ${configPDA.toString()}`);
+    console.log(`Program Data: ${programDataPDA.toString()}`);
+
+    const initializeConfigIx = await program.methods
+      .initializeConfig(admin, new BN(timelockDelay), mints, oracle)
+      .accounts({
+        config: configPDA,
+        authority: wallet.publicKey,
+        program: programId,
+        programData: programDataPDA,
+        systemProgram: SystemProgram.programId,
+      } as any)
+      .instruction();
+
+    const transaction = new Transaction().add(initializeConfigIx);
+
+    console.log("Sending transaction...");
+    const signature = await provider.sendAndConfirm(transaction, []);
+    console.log(`Config initialized successfully! Signature: ${signature}`);
+    return signature;
+  } catch (error) {
+    console.error("Error initializing config:", error);
+    throw error;
+  }
+};
+
+// Main function to parse command line arguments and execute the appropriate function
+async function main() {
+  const args = process.argv.slice(2);
+
+  if (args.length < 1) {
+    console.log("Usage:");
+    console.log(
+      "  npx ts-node cli/initializeConfig.ts <timelock_delay_seconds> [admin_pubkey]"
+    );
+    process.exit(1);
+  }
+
+  const timelockDelay = parseInt(args[0]);
+
+  if (isNaN(timelockDelay) || timelockDelay < 0) {
+    console.error("Invalid timelock delay. Please provide a non-negative number.");
+    process.exit(1);
+  }
+
+  // Default to the upgrade authority wallet as admin
+  const admin = args.length > 1 ? new PublicKey(args[1]) : wallet.publicKey;
+
+  await initializeConfig(admin, timelockDelay);
+}
+
+// Run the main function if this file is executed directly
+if (require.main === module) {
+  main().catch(console.error);
+}
+
+// Export the function for use in other files
+export { initializeConfig };
@@ -5,6 +5,7 @@ import {
   Transaction,
   sendAndConfirmTransaction,
   SystemProgram,
+  LAMPORTS_PER_SOL,
 } from "@solana/web3.js";
 import { Program, AnchorProvider, BN, Wallet } from "@coral-xyz/anchor";
 import {
@@ -107,7 +108,10 @@ const getOrCreateAssociatedTokenAccount = async (
 };
 
 // Function to redeem tokens from the vault
-const redeemTokens = async (tokenMint: PublicKey): Promise<string> => {
+const redeemTokens = async (
+  tokenMint: PublicKey,
+  solAmount: BN | null = null
+): Promise<string> => {
   try {
     console.log(`Redeeming INF tokens from the vault...`);
 
@@ -129,6 +133,17 @@ const redeemTokens = async (tokenMint: PublicKey): Promise<string> => {
       program.programId
     );
 
+    // Default to all principal that isn't locked
+    if (!solAmount) {
+      const userData = await program.account.userData.fetch(userDataPda);
+      const now = Math.floor(Date.now() / 1000);
+      const locked = userData.locks
+        .filter((lock) => lock.expiry.toNumber() > now)
+        .reduce((sum, lock) => sum.add(lock.amount), new BN(0));
+      solAmount = userData.totalSolDeposited.sub(locked);
+    }
+    console.log(`Redeeming ${solAmount.toString()} lamports of principal`);
+
     // Get the user's token account for receiving tokens
     const userTokenAccount = await getOrCreateAssociatedTokenAccount(
       tokenMint,
@@ -157,7 +172,7 @@ const redeemTokens = async (tokenMint: PublicKey): Promise<string> => {
 
     // Create the redemption instruction
     const redeemIx = await program.methods
-      .redeem()
+      .redeem(solAmount)
       .accounts({
         user: wallet.publicKey,
         userData: userDataPda,
@@ -192,13 +207,24 @@ async function main() {
   const SOL = new PublicKey("So11111111111111111111111111111111111111112");
   const INF = new PublicKey("5oVNBeEEQvYi1cX3ir8Dx5n1P7pdxydbGF2X4TxVusJm");
 
-  // According to README, no arguments are needed for redeem command
+  // Optional amount of principal in SOL, defaults to everything redeemable
+  const args = process.argv.slice(2);
+  const amountInSol = args[0] ? parseFloat(args[0]) : null;
+  if (amountInSol !== null && (isNaN(amountInSol) || amountInSol <= 0)) {
+    console.error("Invalid SOL amount. Please provide a positive number.");
+    process.exit(1);
+  }
   console.log("Redeeming tokens...");
 
   // Default to INF token
   const tokenMint = INF;
 
-  await redeemTokens(tokenMint);
+  await redeemTokens(
+    tokenMint,
+    amountInSol !== null
+      ? new BN(Math.floor(amountInSol * LAMPORTS_PER_SOL))
+      : null
+  );
 }
 
 // Run the main function if this file is executed directly
@@ -0,0 +1,242 @@
+import {
+  PublicKey,
+  SystemProgram,
+  TransactionMessage,
+  VersionedTransaction,
+  LAMPORTS_PER_SOL,
+} from "@solana/web3.js";
+import { BN } from "@coral-xyz/anchor";
+import {
+  getAssociatedTokenAddressSync,
+  NATIVE_MINT,
+  TOKEN_PROGRAM_ID,
+} from "@solana/spl-token";
+import dotenv from "dotenv";
+import fetch from "node-fetch";
+import {
+  provider,
+  wallet,
+  program,
+  connection,
+  jupiterProgramId,
+  getAdressLookupTableAccounts,
+  instructionDataToTransactionInstruction,
+} from "./helper";
+
+dotenv.config();
+
+const API_ENDPOINT = "https://quote-api.jup.ag/v6";
+const INF_MINT = new PublicKey("5oVNBeEEQvYi1cX3ir8Dx5n1P7pdxydbGF2X4TxVusJm");
+const INF_PRICE_ACCOUNT = new PublicKey(
+  "Ceg5oePJv1a6RR541qKeQaTepvERA3i8SvyueX9tT8Sq"
+);
+const SOL_PRICE_ACCOUNT = new PublicKey(
+  "7UVimffxr9ow1uXYxsr4LHAcV58mLzhmwaeKvJ1pjLiE"
+);
+
+// Function to read the price and exponent of a Pyth PriceUpdateV2 account
+const readPythPrice = async (
+  address: PublicKey
+): Promise<{ price: BN; exponent: number }> => {
+  const account = await connection.getAccountInfo(address);
+  if (!account) {
+    throw new Error(`Price update account ${address.toBase58()} not found`);
+  }
+  // Discriminator and write authority, then the verification level:
+  // Partial { num_signatures } takes 2 bytes, Full takes 1
+  let offset = 8 + 32;
+  offset += account.data.readUInt8(offset) === 0 ? 2 : 1;
+  // Feed ID, then price (i64), conf (u64) and exponent (i32)
+  offset += 32;
+  return {
+    price: new BN(account.data.subarray(offset, offset + 8), "le").fromTwos(64),
+    exponent: account.data.readInt32LE(offset + 16),
+  };
+};
+
+// Function to redeem principal and receive it as SOL in the same transaction
+const redeemToSol = async (solAmount: BN | null = null): Promise<string> => {
+  try {
+    const [vaultAuthority] = PublicKey.findProgramAddressSync(
+      [Buffer.from("vault_authority")],
+      program.programId
+    );
+    const [userDataPDA] = PublicKey.findProgramAddressSync(
+      [Buffer.from("user_data"), wallet.publicKey.toBuffer()],
+      program.programId
+    );
+    // Per-user authority of the temporary accounts, signs the swap
+    const [redeemAuthority] = PublicKey.findProgramAddressSync(
+      [Buffer.from("redeem_authority"), wallet.publicKey.toBuffer()],
+      program.programId
+    );
+    // Temporary INF and WSOL accounts, the route's source and destination
+    const [tempInfAccount] = PublicKey.findProgramAddressSync(
+      [Buffer.from("redeem_inf"), wallet.publicKey.toBuffer()],
+      program.programId
+    );
+    const [tempWsolAccount] = PublicKey.findProgramAddressSync(
+      [Buffer.from("redeem_sol"), wallet.publicKey.toBuffer()],
+      program.programId
+    );
+    const vaultTokenAccount = getAssociatedTokenAddressSync(
+      INF_MINT,
+      vaultAuthority,
+      true
+    );
+
+    // Default to all principal that isn't locked
+    const userData = await program.account.userData.fetch(userDataPDA);
+    if (!solAmount) {
+      const now = Math.floor(Date.now() / 1000);
+      const locked = userData.locks
+        .filter((lock) => lock.expiry.toNumber() > now)
+        .reduce((sum, lock) => sum.add(lock.amount), new BN(0));
+      solAmount = userData.totalSolDeposited.sub(locked);
+    }
+
+    // Quote what the program will pay out: the principal's value at the Pyth INF per
+    // SOL rate, capped at its share of the deposited INF. A small margin absorbs price
+    // updates before the transaction lands, any INF the route leaves is sent back
+    const baseInfAmount = userData.totalInfEquivalent
+      .mul(solAmount)
+      .div(userData.totalSolDeposited);
+    const [infPrice, solPrice] = await Promise.all([
+      readPythPrice(INF_PRICE_ACCOUNT),
+      readPythPrice(SOL_PRICE_ACCOUNT),
+    ]);
+    const exponent = Math.min(infPrice.exponent, solPrice.exponent);
+    const infValue = infPrice.price.mul(
+      new BN(10).pow(new BN(infPrice.exponent - exponent))
+    );
+    const solValue = solPrice.price.mul(
+      new BN(10).pow(new BN(solPrice.exponent - exponent))
+    );
+    const currentRate = solValue.muln(1_000_000).div(infValue);
+    const payout = solAmount.mul(currentRate).divn(1_000_000);
+    const cappedPayout = BN.min(payout, baseInfAmount);
+    const infAmount = cappedPayout.muln(9_990).divn(10_000);
+    console.log(
+      `Redeeming ${solAmount.toString()} lamports of principal, swapping ${infAmount.toString()} INF to SOL...`
+    );
+
+    const quote = await fetch(
+      `${API_ENDPOINT}/quote?inputMint=${INF_MINT.toBase58()}&outputMint=${NATIVE_MINT.toBase58()}&amount=${infAmount.toString()}&slippageBps=50`
+    ).then((response) => response.json());
+    if ("error" in quote) {
+      throw new Error(`Failed to get Jupiter quote: ${quote.error}`);
+    }
+
+    // The redeem authority owns the INF once it leaves the vault and signs the swap through the program
+    const jupResponse = await fetch(`${API_ENDPOINT}/swap-instructions`, {
+      method: "POST",
+      headers: {
+        Accept: "application/json",
+        "Content-Type": "application/json",
+      },
+      body: JSON.stringify({
+        quoteResponse: quote,
+        userPublicKey: redeemAuthority.toBase58(),
+        sourceTokenAccount: tempInfAccount.toBase58(),
+        destinationTokenAccount: tempWsolAccount.toBase58(),
+        useSharedAccounts: true,
+        config: {
+          skipUserAccountsRpcCalls: true,
+          wrapAndUnwrapSol: false,
+        },
+      }),
+    }).then((response) => response.json());
+    if ("error" in jupResponse) {
+      throw new Error(`Failed to get swap instructions: ${jupResponse.error}`);
+    }
+
+    const swapInstruction = instructionDataToTransactionInstruction(
+      jupResponse.swapInstruction
+    );
+    const remainingAccounts = swapInstruction.keys.map((account) => ({
+      ...account,
+      // The program signs for the redeem authority, nothing is signed in the transaction
+      isSigner: false,
+    }));
+
+    const redeemToSolIx = await program.methods
+      .redeemToSol(
+        solAmount,
+        swapInstruction.data,
+        // Jupiter's slippage-adjusted minimum output
+        new BN(quote.otherAmountThreshold)
+      )
+      .accounts({
+        user: wallet.publicKey,
+        userData: userDataPDA,
+        infMint: INF_MINT,
+        infMintProgram: TOKEN_PROGRAM_ID,
+        wsolMint: NATIVE_MINT,
+        wsolMintProgram: TOKEN_PROGRAM_ID,
+        vaultTokenAccount,
+        userInfTokenAccount: getAssociatedTokenAddressSync(
+          INF_MINT,
+          wallet.publicKey
+        ),
+        tempInfAccount,
+        tempWsolAccount,
+        redeemAuthority,
+        vaultAuthority,
+        jupiterProgram: jupiterProgramId,
+        systemProgram: SystemProgram.programId,
+        priceUpdateInf: INF_PRICE_ACCOUNT,
+        priceUpdateSol: SOL_PRICE_ACCOUNT,
+      } as any)
+      .remainingAccounts(remainingAccounts)
+      .instruction();
+
+    const messageV0 = new TransactionMessage({
+      payerKey: wallet.publicKey,
+      recentBlockhash: (await connection.getLatestBlockhash()).blockhash,
+      instructions: [
+        ...jupResponse.computeBudgetInstructions.map(
+          instructionDataToTransactionInstruction
+        ),
+        redeemToSolIx,
+      ],
+    }).compileToV0Message(
+      await getAdressLookupTableAccounts(jupResponse.addressLookupTableAddresses)
+    );
+
+    console.log("Sending transaction...");
+    const signature = await provider.sendAndConfirm(
+      new VersionedTransaction(messageV0),
+      [wallet.payer]
+    );
+    console.log(`Redeemed to SOL! Signature: ${signature}`);
+    return signature;
+  } catch (error) {
+    console.error("Error redeeming to SOL:", error);
+    throw error;
+  }
+};
+
+// Main function to parse command line arguments and execute the appropriate function
+async function main() {
+  // Optional amount of principal in SOL, defaults to everything redeemable
+  const args = process.argv.slice(2);
+  const amountInSol = args[0] ? parseFloat(args[0]) : null;
+  if (amountInSol !== null && (isNaN(amountInSol) || amountInSol <= 0)) {
+    console.error("Invalid SOL amount. Please provide a positive number.");
+    process.exit(1);
+  }
+
+  await redeemToSol(
+    amountInSol !== null
+      ? new BN(Math.floor(amountInSol * LAMPORTS_PER_SOL))
+      : null
+  );
+}
+
+// Run the main function if this file is executed directly
+if (require.main === module) {
+  main().catch(console.error);
+}
+
+// Export the function for use in other files
+export { redeemToSol };
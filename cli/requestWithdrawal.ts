@@ -0,0 +1,69 @@
+import { PublicKey, Transaction, LAMPORTS_PER_SOL } from "@solana/web3.js";
+import { BN } from "@coral-xyz/anchor";
+import { getAssociatedTokenAddressSync, TOKEN_PROGRAM_ID } from "@solana/spl-token";
+import dotenv from "dotenv";
+import { provider, wallet, program } from "./helper";
+
+dotenv.config();
+
+const INF_MINT = new PublicKey("5oVNBeEEQvYi1cX3ir8Dx5n1P7pdxydbGF2X4TxVusJm");
+
+// Function to queue a withdrawal while a round is active
+const requestWithdrawal = async (solAmount: BN): Promise<string> => {
+  try {
+    console.log(
+      `Requesting withdrawal of ${solAmount.toString()} lamports of principal...`
+    );
+
+    const requestWithdrawalIx = await program.methods
+      .requestWithdrawal(solAmount)
+      .accounts({
+        user: wallet.publicKey,
+        tokenMint: INF_MINT,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        // Receives the INF when the ticket is fulfilled
+        userTokenAccount: getAssociatedTokenAddressSync(
+          INF_MINT,
+          wallet.publicKey
+        ),
+      } as any)
+      .instruction();
+
+    const transaction = new Transaction().add(requestWithdrawalIx);
+
+    console.log("Sending transaction...");
+    const signature = await provider.sendAndConfirm(transaction, []);
+    console.log(`Withdrawal requested! Signature: ${signature}`);
+    return signature;
+  } catch (error) {
+    console.error("Error requesting withdrawal:", error);
+    throw error;
+  }
+};
+
+// Main function to parse command line arguments and execute the appropriate function
+async function main() {
+  const args = process.argv.slice(2);
+
+  if (args.length < 1) {
+    console.log("Usage:");
+    console.log("  npx ts-node cli/requestWithdrawal.ts <amount_in_sol>");
+    process.exit(1);
+  }
+
+  const amountInSol = parseFloat(args[0]);
+  if (isNaN(amountInSol) || amountInSol <= 0) {
+    console.error("Invalid SOL amount. Please provide a positive number.");
+    process.exit(1);
+  }
+
+  await requestWithdrawal(new BN(Math.floor(amountInSol * LAMPORTS_PER_SOL)));
+}
+
+// Run the main function if this file is executed directly
+if (require.main === module) {
+  main().catch(console.error);
+}
+
+// Export the function for use in other files
+export { requestWithdrawal };
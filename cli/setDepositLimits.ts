@@ -0,0 +1,75 @@
+import { Transaction, LAMPORTS_PER_SOL } from "@solana/web3.js";
+import { BN } from "@coral-xyz/anchor";
+import dotenv from "dotenv";
+import { provider, wallet, program } from "./helper";
+
+dotenv.config();
+
+const toLamports = (sol: number) => new BN(Math.floor(sol * LAMPORTS_PER_SOL));
+
+// Function to update the minimum deposit and principal caps (admin only)
+const setDepositLimits = async (
+  minDepositSol: number,
+  maxUserPrincipalSol: number,
+  maxTotalPrincipalSol: number
+): Promise<string> => {
+  try {
+    console.log(
+      `Setting deposit limits: min ${minDepositSol} SOL, per user ${maxUserPrincipalSol} SOL, total ${maxTotalPrincipalSol} SOL...`
+    );
+
+    const setDepositLimitsIx = await program.methods
+      .setDepositLimits({
+        minDeposit: toLamports(minDepositSol),
+        maxUserPrincipal: toLamports(maxUserPrincipalSol),
+        maxTotalPrincipal: toLamports(maxTotalPrincipalSol),
+      })
+      .accounts({
+        authority: wallet.publicKey,
+      } as any)
+      .instruction();
+
+    const transaction = new Transaction().add(setDepositLimitsIx);
+
+    console.log("Sending transaction...");
+    const signature = await provider.sendAndConfirm(transaction, []);
+    console.log(`Deposit limits updated! Signature: ${signature}`);
+    return signature;
+  } catch (error) {
+    console.error("Error setting deposit limits:", error);
+    throw error;
+  }
+};
+
+// Main function to parse command line arguments and execute the appropriate function
+async function main() {
+  const args = process.argv.slice(2);
+
+  if (args.length < 3) {
+    console.log("Usage:");
+    console.log(
+      "  npx ts-node cli/setDepositLimits.ts <min_deposit_sol> <max_user_principal_sol> <max_total_principal_sol>"
+    );
+    console.log("  A cap of 0 means no cap.");
+    process.exit(1);
+  }
+
+  const [minDeposit, maxUserPrincipal, maxTotalPrincipal] = args
+    .slice(0, 3)
+    .map(parseFloat);
+
+  if ([minDeposit, maxUserPrincipal, maxTotalPrincipal].some((x) => isNaN(x) || x < 0)) {
+    console.error("Invalid limits. Please provide non-negative numbers.");
+    process.exit(1);
+  }
+
+  await setDepositLimits(minDeposit, maxUserPrincipal, maxTotalPrincipal);
+}
+
+// Run the main function if this file is executed directly
+if (require.main === module) {
+  main().catch(console.error);
+}
+
+// Export the function for use in other files
+export { setDepositLimits };
@@ -14,11 +14,75 @@ pub enum RedeemError {
     InvalidPriceData,
     #[msg("Exchange rate decreased, cannot redeem at this time")]
     ExchangeRateDecrease,
+    #[msg("Redeem amount must be positive and at most the user's principal")]
+    InvalidRedeemAmount,
+    #[msg("Swap returned less SOL than allowed")]
+    SolOutputTooLow,
+}
+
+#[error_code]
+pub enum WithdrawalError {
+    #[msg("No round is active, redeem directly instead")]
+    RoundNotActive,
+    #[msg("The round of this withdrawal has not closed yet")]
+    RoundNotClosed,
+    #[msg("A withdrawal is pending for this user")]
+    WithdrawalPending,
+}
+
+#[error_code]
+pub enum DepositError {
+    #[msg("Deposit is below the minimum deposit")]
+    BelowMinimumDeposit,
+    #[msg("Deposit would exceed the per-user principal cap")]
+    UserCapExceeded,
+    #[msg("Deposit would exceed the vault principal cap")]
+    VaultCapExceeded,
+    #[msg("Minimum deposit exceeds a principal cap")]
+    InvalidDepositLimits,
+}
+
+#[error_code]
+pub enum LockError {
+    #[msg("Lock duration does not match a configured lock tier")]
+    InvalidLockDuration,
+    #[msg("Lock tiers must have distinct positive durations and multipliers of at least 1x")]
+    InvalidLockTiers,
+    #[msg("Too many lock tiers")]
+    LockTiersFull,
+    #[msg("All remaining principal is locked")]
+    PrincipalLocked,
+    #[msg("Too many active locks, wait for one to expire")]
+    TooManyLocks,
+}
+
+#[error_code]
+pub enum ReferralError {
+    #[msg("Users cannot refer themselves")]
+    SelfReferral,
+    #[msg("A referrer is already bound to this user")]
+    ReferrerAlreadySet,
+    #[msg("Users who already refer others cannot bind a referrer")]
+    ReferralCycle,
+    #[msg("Referrer accounts are required for this user")]
+    MissingReferrerAccounts,
+    #[msg("Referrer accounts do not match the bound referrer")]
+    InvalidReferrerAccounts,
+    #[msg("Referral bonus is out of range")]
+    InvalidReferralBonus,
+    #[msg("No referral bonus to claim")]
+    NoPendingBonus,
+}
+
+#[error_code]
+pub enum ReceiptError {
+    #[msg("Deposit receipts can only be closed once the principal is redeemed")]
+    PrincipalNotRedeemed,
 }
 
 #[error_code]
 pub enum SwapError {
-    #[msg("Invalid input mint. Expected WSOL")]
+    #[msg("Invalid input mint. Expected WSOL or an accepted LST")]
     InvalidInputMint,
     #[msg("Invalid output mint. Expected INF")]
     InvalidOutputMint,
@@ -28,6 +92,28 @@ pub enum SwapError {
     InvalidVaultTokenAccount,
     #[msg("Invalid swap direction")]
     InvalidSwapDirection,
+    #[msg("Deposit amount must be greater than zero")]
+    ZeroAmount,
+    #[msg("Swap spent more SOL or returned less INF than allowed")]
+    SlippageExceeded,
+    #[msg("Swap rate deviates too far from the oracle rate")]
+    OracleRateDeviation,
+}
+
+#[error_code]
+pub enum JupiterError {
+    #[msg("Jupiter instruction is not an accepted route")]
+    InvalidRouteInstruction,
+    #[msg("Not enough accounts for the Jupiter route")]
+    InvalidRouteAccounts,
+    #[msg("Jupiter route transfer authority is not the expected signer")]
+    InvalidRouteAuthority,
+    #[msg("Jupiter route source is not the expected token account")]
+    InvalidRouteSource,
+    #[msg("Jupiter route destination is not the expected token account")]
+    InvalidRouteDestination,
+    #[msg("Jupiter route accounts contain an unexpected signer")]
+    UnexpectedSigner,
 }
 
 #[error_code]
@@ -50,4 +136,64 @@ pub enum RoundError {
     UnauthorizedUser,
     #[msg("Insufficient funds for this operation")]
     InsufficientFunds,
-} 
\ No newline at end of file
+}
+
+#[error_code]
+pub enum ConfigError {
+    #[msg("Signer is not the program upgrade authority")]
+    InvalidUpgradeAuthority,
+    #[msg("No admin handover is pending")]
+    NoPendingAdmin,
+    #[msg("Signer is not the pending admin")]
+    InvalidPendingAdmin,
+    #[msg("Invalid accepted LST entry")]
+    InvalidAcceptedLst,
+    #[msg("LST is already accepted")]
+    LstAlreadyAccepted,
+    #[msg("LST is not accepted")]
+    LstNotAccepted,
+    #[msg("Accepted LST list is full")]
+    AcceptedLstsFull,
+}
+
+#[error_code]
+pub enum OracleError {
+    #[msg("Oracle price must be positive")]
+    InvalidPrice,
+    #[msg("Oracle confidence interval is too wide")]
+    ConfidenceTooWide,
+    #[msg("Invalid oracle configuration")]
+    InvalidOracleConfig,
+    #[msg("Missing price update account for the input mint")]
+    MissingPriceUpdate,
+}
+
+#[error_code]
+pub enum TimelockError {
+    #[msg("Timelock delay is out of range")]
+    InvalidTimelockDelay,
+    #[msg("Timelock has not expired yet")]
+    TimelockNotExpired,
+}
+
+#[error_code]
+pub enum RoleError {
+    #[msg("Signer does not hold the required role")]
+    MissingRole,
+    #[msg("Member already holds this role")]
+    RoleAlreadyGranted,
+    #[msg("Member does not hold this role")]
+    RoleNotGranted,
+    #[msg("Role registry is full")]
+    RoleRegistryFull,
+}
+
+#[error_code]
+pub enum PausedError {
+    #[msg("This operation is currently paused")]
+    OperationPaused,
+    #[msg("Pause mask contains unknown flags")]
+    InvalidPauseMask,
+    #[msg("Only the admin can lift a pause")]
+    UnpauseRequiresAdmin,
+}
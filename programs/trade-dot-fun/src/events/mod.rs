@@ -0,0 +1,202 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{AdminAction, DepositLimits, Role};
+
+#[event]
+pub struct ActionQueued {
+    pub action_id: u64,
+    pub action: AdminAction,
+    pub proposer: Pubkey,
+    pub eta: i64,  // Earliest timestamp the action can be executed
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ActionExecuted {
+    pub action_id: u64,
+    pub action: AdminAction,
+    pub executor: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ActionCancelled {
+    pub action_id: u64,
+    pub action: AdminAction,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AdminAccepted {
+    pub admin: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct RoleGranted {
+    pub role: Role,
+    pub member: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct RoleRevoked {
+    pub role: Role,
+    pub member: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct PauseUpdated {
+    pub previous: u8,
+    pub paused: u8,
+    pub authority: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct RoundInitialized {
+    pub round_number: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct RoundStarted {
+    pub round_number: u64,
+    pub start_time: i64,
+}
+
+#[event]
+pub struct RoundClosed {
+    pub round_number: u64,
+    pub total_sol_deposited: u64,
+    pub total_inf_received: u64,
+    pub total_chips: u64,
+    pub end_time: i64,
+}
+
+#[event]
+pub struct Deposited {
+    pub user: Pubkey,
+    pub round_number: u64,
+    pub sol_deposited: u64,
+    pub inf_received: u64,
+    pub exchange_rate: u64,  // INF per SOL (scaled by 10^6)
+    pub chips_minted: u64,
+    pub lock_expiry: i64,  // Expiry of this deposit's lock if it was locked, otherwise 0
+    pub total_principal_sol: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct Participated {
+    pub user: Pubkey,
+    pub round_number: u64,
+    pub chips: u64,
+    pub total_chips: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct RewardDistributed {
+    pub round_number: u64,
+    pub winner: Pubkey,
+    pub mint: Pubkey,
+    pub ratio: u64,  // Percentage of the round reward
+    pub share: u64,
+    pub total_reward: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ChipsBurned {
+    pub round_number: u64,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct Redeemed {
+    pub user: Pubkey,
+    pub sol_principal: u64,
+    pub base_inf_amount: u64,
+    pub inf_redeemed: u64,
+    pub initial_exchange_rate: u64,  // INF per SOL at deposit (scaled by 10^6)
+    pub current_exchange_rate: u64,  // INF per SOL from Pyth (scaled by 10^6)
+    pub remaining_principal_sol: u64,  // User principal left after this redemption
+    pub total_principal_sol: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct DepositLimitsUpdated {
+    pub previous: DepositLimits,
+    pub limits: DepositLimits,
+    pub authority: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ReferrerBound {
+    pub user: Pubkey,
+    pub referrer: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ReferralRewarded {
+    pub referrer: Pubkey,
+    pub user: Pubkey,
+    pub sol_deposited: u64,
+    pub bonus_chips: u64,
+    pub deferred: bool,  // Bonus credited to Referrer.pending_bonus_chips instead of minted
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ReferralBonusClaimed {
+    pub referrer: Pubkey,
+    pub bonus_chips: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct DepositedFor {
+    pub payer: Pubkey,
+    pub beneficiary: Pubkey,
+    pub round_number: u64,
+    pub sol_deposited: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct RedeemedToSol {
+    pub user: Pubkey,
+    pub inf_swapped: u64,
+    pub sol_received: u64,  // Lamports unwrapped to the user
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct WithdrawalRequested {
+    pub user: Pubkey,
+    pub sol_amount: u64,
+    pub round_number: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct WithdrawalFulfilled {
+    pub user: Pubkey,
+    pub fulfiller: Pubkey,
+    pub sol_amount: u64,
+    pub inf_redeemed: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct WithdrawalCancelled {
+    pub user: Pubkey,
+    pub sol_amount: u64,
+    pub timestamp: i64,
+}
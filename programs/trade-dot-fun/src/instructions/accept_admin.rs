@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+use crate::{
+    state::Config,
+    errors::ConfigError,
+    events::AdminAccepted,
+};
+
+#[derive(Accounts)]
+pub struct AcceptAdmin<'info> {
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    pub new_admin: Signer<'info>,
+}
+
+pub fn accept_admin(ctx: Context<AcceptAdmin>) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    let pending_admin = config.pending_admin.ok_or(ConfigError::NoPendingAdmin)?;
+    require_keys_eq!(
+        ctx.accounts.new_admin.key(),
+        pending_admin,
+        ConfigError::InvalidPendingAdmin
+    );
+
+    config.admin = pending_admin;
+    config.pending_admin = None;
+
+    emit!(AdminAccepted {
+        admin: pending_admin,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!("Admin handover accepted by: {}", pending_admin);
+    Ok(())
+}
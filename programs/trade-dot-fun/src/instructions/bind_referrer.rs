@@ -0,0 +1,88 @@
+use anchor_lang::prelude::*;
+use crate::{
+    debug_msg,
+    state::{Referrer, UserData},
+    errors::ReferralError,
+    events::ReferrerBound,
+};
+
+// Space: 8 (discriminator) + 32 (referrer) + 8 (referee_count) + 8 (referred_principal_sol)
+// + 8 (bonus_chips) + 8 (pending_bonus_chips) + 1 (bump)
+pub const REFERRER_SPACE: usize = 8 + 32 + 8 + 8 + 8 + 8 + 1;
+
+#[derive(Accounts)]
+#[instruction(referrer: Pubkey)]
+pub struct BindReferrer<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"user_data", user.key().as_ref()],
+        bump,
+    )]
+    pub user_data: Account<'info, UserData>,
+
+    // Created by the first referee to bind this referrer
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = REFERRER_SPACE,
+        seeds = [b"referrer", referrer.as_ref()],
+        bump,
+    )]
+    pub referrer_stats: Account<'info, Referrer>,
+
+    /// CHECK: The user's own referrer stats, which usually don't exist. Only read
+    /// to reject cycles.
+    #[account(
+        seeds = [b"referrer", user.key().as_ref()],
+        bump,
+    )]
+    pub user_referrer_stats: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn bind_referrer(ctx: Context<BindReferrer>, referrer: Pubkey) -> Result<()> {
+    let user = ctx.accounts.user.key();
+    debug_msg!("Step 1: Checking referrer {} for user {}", referrer, user);
+    require_keys_neq!(referrer, user, ReferralError::SelfReferral);
+    require!(
+        ctx.accounts.user_data.referrer.is_none(),
+        ReferralError::ReferrerAlreadySet
+    );
+
+    // Users who already have referees cannot bind a referrer, so every chain ends
+    // at a user nobody refers and no binding can close a loop
+    let user_referrer_stats = ctx.accounts.user_referrer_stats.to_account_info();
+    if user_referrer_stats.owner == &crate::ID && !user_referrer_stats.data_is_empty() {
+        let stats = Referrer::try_deserialize(&mut &user_referrer_stats.try_borrow_data()?[..])?;
+        require!(stats.referee_count == 0, ReferralError::ReferralCycle);
+    }
+
+    debug_msg!("Step 2: Binding referrer");
+    ctx.accounts.user_data.referrer = Some(referrer);
+
+    let referrer_stats = &mut ctx.accounts.referrer_stats;
+    // A zeroed referrer key means the stats account was just created
+    if referrer_stats.referrer == Pubkey::default() {
+        referrer_stats.referrer = referrer;
+        referrer_stats.referred_principal_sol = 0;
+        referrer_stats.bonus_chips = 0;
+        referrer_stats.pending_bonus_chips = 0;
+        referrer_stats.bump = ctx.bumps.referrer_stats;
+    }
+    referrer_stats.referee_count = referrer_stats.referee_count
+        .checked_add(1)
+        .unwrap();
+
+    emit!(ReferrerBound {
+        user,
+        referrer,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!("Referrer bound: user={} referrer={}", user, referrer);
+    Ok(())
+}
\ No newline at end of file
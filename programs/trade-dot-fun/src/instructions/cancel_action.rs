@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+use crate::{
+    state::{Config, PendingAction},
+    events::ActionCancelled,
+    utils::check_authorized_admin,
+};
+
+#[derive(Accounts)]
+#[instruction(action_id: u64)]
+pub struct CancelAction<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        seeds = [b"pending_action", action_id.to_le_bytes().as_ref()],
+        bump = pending_action.bump,
+        has_one = proposer,
+        close = proposer,
+    )]
+    pub pending_action: Account<'info, PendingAction>,
+
+    /// CHECK: Rent receiver, checked against pending_action.proposer
+    #[account(mut)]
+    pub proposer: AccountInfo<'info>,
+
+    pub authority: Signer<'info>,
+}
+
+pub fn cancel_action(ctx: Context<CancelAction>, action_id: u64) -> Result<()> {
+    // Check that the authority is the authorized admin
+    check_authorized_admin(&ctx.accounts.authority.key(), &ctx.accounts.config)?;
+
+    emit!(ActionCancelled {
+        action_id,
+        action: ctx.accounts.pending_action.action.clone(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!("Action {} cancelled", action_id);
+    Ok(())
+}
@@ -0,0 +1,34 @@
+use anchor_lang::prelude::*;
+use crate::{
+    state::WithdrawalTicket,
+    events::WithdrawalCancelled,
+};
+
+#[derive(Accounts)]
+pub struct CancelWithdrawal<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    // Fulfilled tickets are already closed, so only pending ones can be cancelled
+    #[account(
+        mut,
+        close = user,
+        seeds = [b"withdrawal_ticket", user.key().as_ref()],
+        bump = withdrawal_ticket.bump,
+        has_one = user,
+    )]
+    pub withdrawal_ticket: Account<'info, WithdrawalTicket>,
+}
+
+pub fn cancel_withdrawal(ctx: Context<CancelWithdrawal>) -> Result<()> {
+    let sol_amount = ctx.accounts.withdrawal_ticket.sol_amount;
+
+    emit!(WithdrawalCancelled {
+        user: ctx.accounts.user.key(),
+        sol_amount,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!("Withdrawal cancelled: user={} sol={}", ctx.accounts.user.key(), sol_amount);
+    Ok(())
+}
\ No newline at end of file
@@ -0,0 +1,88 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{mint_to, Mint, MintTo, TokenAccount, TokenInterface},
+};
+use crate::{
+    state::{Config, Referrer},
+    errors::ReferralError,
+    events::ReferralBonusClaimed,
+    utils::find_vault_authority_pda,
+};
+
+// Mints the referral bonus deposits couldn't pay because no referrer CHIP account was passed
+#[derive(Accounts)]
+pub struct ClaimReferralBonus<'info> {
+    #[account(mut)]
+    pub referrer: Signer<'info>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        seeds = [b"referrer", referrer.key().as_ref()],
+        bump = referrer_stats.bump,
+    )]
+    pub referrer_stats: Account<'info, Referrer>,
+
+    #[account(
+        mut,
+        address = config.mints.chip
+    )]
+    pub reward_mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        init_if_needed,
+        payer = referrer,
+        associated_token::mint = reward_mint,
+        associated_token::authority = referrer,
+        associated_token::token_program = reward_mint_program,
+    )]
+    pub referrer_reward_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// CHECK: This is the program's vault authority PDA
+    #[account(
+        seeds = [b"vault_authority"],
+        bump,
+    )]
+    pub vault_authority: AccountInfo<'info>,
+
+    pub reward_mint_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn claim_referral_bonus(ctx: Context<ClaimReferralBonus>) -> Result<()> {
+    let bonus_chips = ctx.accounts.referrer_stats.pending_bonus_chips;
+    require!(bonus_chips > 0, ReferralError::NoPendingBonus);
+    ctx.accounts.referrer_stats.pending_bonus_chips = 0;
+
+    let (_, bump) = find_vault_authority_pda();
+    let vault_authority_seeds = &[b"vault_authority".as_ref(), &[bump]];
+    let signer_seeds = &[&vault_authority_seeds[..]];
+    mint_to(
+        CpiContext::new_with_signer(
+            ctx.accounts.reward_mint_program.to_account_info(),
+            MintTo {
+                mint: ctx.accounts.reward_mint.to_account_info(),
+                to: ctx.accounts.referrer_reward_token_account.to_account_info(),
+                authority: ctx.accounts.vault_authority.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        bonus_chips,
+    )?;
+
+    emit!(ReferralBonusClaimed {
+        referrer: ctx.accounts.referrer.key(),
+        bonus_chips,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!("Referral bonus claimed: referrer={} chips={}", ctx.accounts.referrer.key(), bonus_chips);
+    Ok(())
+}
\ No newline at end of file
@@ -0,0 +1,38 @@
+use anchor_lang::prelude::*;
+use crate::{
+    state::{DepositReceipt, UserData},
+    errors::ReceiptError,
+};
+
+#[derive(Accounts)]
+#[instruction(deposit_index: u64)]
+pub struct CloseDepositReceipt<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    #[account(
+        seeds = [b"user_data", user.key().as_ref()],
+        bump,
+    )]
+    pub user_data: Account<'info, UserData>,
+
+    #[account(
+        mut,
+        seeds = [b"deposit_receipt", user.key().as_ref(), deposit_index.to_le_bytes().as_ref()],
+        bump,
+        has_one = user,
+        close = user,
+    )]
+    pub deposit_receipt: Account<'info, DepositReceipt>,
+}
+
+pub fn close_deposit_receipt(ctx: Context<CloseDepositReceipt>, deposit_index: u64) -> Result<()> {
+    // Receipts back the principal, so keep them until it has been redeemed
+    require!(
+        ctx.accounts.user_data.total_sol_deposited == 0,
+        ReceiptError::PrincipalNotRedeemed
+    );
+
+    msg!("Deposit receipt {} closed for: {}", deposit_index, ctx.accounts.user.key());
+    Ok(())
+}
@@ -1,8 +1,10 @@
 use anchor_lang::prelude::*;
 use crate::{
-    state::{Round, RoundState, VaultData},
+    debug_msg,
+    state::{Role, RoleRegistry, Round, RoundState, VaultData},
     errors::RoundError,
-    utils::check_authorized_admin,
+    events::RoundClosed,
+    utils::check_role,
 };
 
 #[derive(Accounts)]
@@ -24,19 +26,34 @@ pub struct CloseRound<'info> {
     
     #[account(mut)]
     pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [b"role_registry"],
+        bump = role_registry.bump,
+    )]
+    pub role_registry: Account<'info, RoleRegistry>,
 }
 
 pub fn close_round(ctx: Context<CloseRound>, round_number: u64) -> Result<()> {
-    // Check that the authority is the authorized admin
-    check_authorized_admin(&ctx.accounts.authority.key())?;
+    // Check that the authority holds the round operator role
+    check_role(&ctx.accounts.authority.key(), &ctx.accounts.role_registry, Role::RoundOperator)?;
     
     let round = &mut ctx.accounts.round;
     require!(round.state == RoundState::Started, RoundError::InvalidRoundState);
     round.state = RoundState::Closed;
     round.end_time = Clock::get()?.unix_timestamp;
+    ctx.accounts.vault_data.is_round_active = false;
+
+    emit!(RoundClosed {
+        round_number,
+        total_sol_deposited: round.total_sol_deposited,
+        total_inf_received: round.total_inf_received,
+        total_chips: round.total_chips,
+        end_time: round.end_time,
+    });
     
     msg!("Round ended: {}", round_number);
-    msg!("Total SOL deposited: {}", round.total_sol_deposited);
-    msg!("Total INF received: {}", round.total_inf_received);
+    debug_msg!("Total SOL deposited: {}", round.total_sol_deposited);
+    debug_msg!("Total INF received: {}", round.total_inf_received);
     Ok(())
 } 
\ No newline at end of file
@@ -0,0 +1,223 @@
+use anchor_lang::{
+    prelude::*,
+    solana_program::program_error::ProgramError,
+};
+use anchor_spl::{associated_token::AssociatedToken, token_interface::{Mint, TokenAccount, TokenInterface}};
+use pyth_solana_receiver_sdk::price_update::PriceUpdateV2;
+
+use crate::{
+    debug_msg,
+    instructions::deposit_sol::DEPOSIT_RECEIPT_SPACE,
+    instructions::initialize_user_data::USER_DATA_SPACE,
+    state::{Config, DepositReceipt, Referrer, Round, UserData, VaultData},
+    errors::SwapError,
+    events::DepositedFor,
+    utils::{jupiter_program_id, check_not_paused, PAUSE_DEPOSIT},
+    utils::deposit::{check_deposit_rate, check_deposit_round, check_slippage, get_input_sol_value,
+                     record_deposit, swap_into_vault, DepositAccounts},
+};
+
+// Same as UserSwap, but the payer's input is swapped and the deposit is credited to the beneficiary
+#[derive(Accounts)]
+#[instruction(round_number: u64)]
+pub struct DepositFor<'info> {
+    // Payer owns the input tokens, signs the swap and pays rent
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: Any wallet, only used as the owner of the credited user data and CHIP account
+    pub beneficiary: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        constraint = input_mint.key() == config.mints.wsol
+            || config.find_accepted_lst(&input_mint.key()).is_some() @ SwapError::InvalidInputMint
+    )]
+    pub input_mint: InterfaceAccount<'info, Mint>,
+    pub input_mint_program: Interface<'info, TokenInterface>,
+
+    #[account(
+        constraint = output_mint.key() == config.mints.inf @ SwapError::InvalidOutputMint
+    )]
+    pub output_mint: InterfaceAccount<'info, Mint>,
+    pub output_mint_program: Interface<'info, TokenInterface>,
+
+    // Payer's input token account, for the input mint checked above
+    #[account(
+        mut,
+        associated_token::mint = input_mint,
+        associated_token::authority = payer,
+        associated_token::token_program = input_mint_program,
+    )]
+    pub payer_input_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        associated_token::mint = output_mint,
+        associated_token::authority = vault_authority,
+        associated_token::token_program = output_mint_program,
+        constraint = vault_token_account.mint == config.mints.inf @ SwapError::InvalidVaultTokenAccount
+    )]
+    pub vault_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// CHECK: This is the program's vault authority PDA
+    #[account(
+        seeds = [b"vault_authority"],
+        bump,
+    )]
+    pub vault_authority: AccountInfo<'info>,
+
+    /// CHECK: This is Jupiter program with address verified
+    #[account(
+        address = jupiter_program_id() @ ProgramError::IncorrectProgramId
+    )]
+    pub jupiter_program: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        address = config.mints.chip
+    )]
+    pub reward_mint: InterfaceAccount<'info, Mint>,
+
+    // Created on the beneficiary's first deposit
+    #[account(
+        init_if_needed,
+        payer = payer,
+        associated_token::mint = reward_mint,
+        associated_token::authority = beneficiary,
+        associated_token::token_program = reward_mint_program,
+    )]
+    pub beneficiary_reward_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    pub reward_mint_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+
+    // The beneficiary's user data, so only the beneficiary can redeem the principal
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = USER_DATA_SPACE,
+        seeds = [b"user_data", beneficiary.key().as_ref()],
+        bump,
+    )]
+    pub user_data: Account<'info, UserData>,
+
+    // Optional per-deposit receipt, seeded by the beneficiary's deposit counter
+    #[account(
+        init,
+        payer = payer,
+        space = DEPOSIT_RECEIPT_SPACE,
+        seeds = [b"deposit_receipt", beneficiary.key().as_ref(), user_data.deposit_count.to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub deposit_receipt: Option<Account<'info, DepositReceipt>>,
+
+    #[account(
+        mut,
+        seeds = [b"vault_data"],
+        bump,
+    )]
+    pub vault_data: Account<'info, VaultData>,
+
+    #[account(
+        mut,
+        seeds = [b"round", round_number.to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub round: Account<'info, Round>,
+
+    // The beneficiary's referrer stats and CHIP account, required when user_data.referrer is set
+    #[account(mut)]
+    pub referrer_stats: Option<Account<'info, Referrer>>,
+
+    #[account(mut)]
+    pub referrer_reward_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    // Pyth INF/USD, SOL/USD and, unless the input is WSOL, the input LST's USD feed
+    pub price_update_inf: Account<'info, PriceUpdateV2>,
+    pub price_update_sol: Account<'info, PriceUpdateV2>,
+    pub price_update_lst: Option<Account<'info, PriceUpdateV2>>,
+}
+
+pub fn deposit_for(
+    ctx: Context<DepositFor>,
+    round_number: u64,
+    data: Vec<u8>,
+    min_inf_out: u64,
+    max_sol_in: u64,
+) -> Result<()> {
+    debug_msg!("Step 1: Starting deposit for {}", ctx.accounts.beneficiary.key());
+    check_not_paused(&ctx.accounts.vault_data, PAUSE_DEPOSIT)?;
+
+    debug_msg!("Step 2: Verifying round state and number");
+    check_deposit_round(&ctx.accounts.round, round_number)?;
+
+    // The payer signs the route, only the crediting differs from deposit_sol
+    debug_msg!("Step 3: Executing Jupiter swap");
+    let (input_spent, inf_received) = swap_into_vault(
+        &ctx.accounts.jupiter_program.key(),
+        ctx.remaining_accounts,
+        data,
+        ctx.accounts.payer.key(),
+        &mut ctx.accounts.payer_input_token_account,
+        &mut ctx.accounts.vault_token_account,
+    )?;
+    check_slippage(input_spent, inf_received, max_sol_in, min_inf_out)?;
+
+    debug_msg!("Step 4: Valuing input and checking the swap rate");
+    let sol_deposited = get_input_sol_value(
+        &ctx.accounts.config,
+        &ctx.accounts.input_mint,
+        ctx.accounts.price_update_lst.as_ref(),
+        &ctx.accounts.price_update_sol,
+        input_spent,
+    )?;
+    check_deposit_rate(
+        &ctx.accounts.config.oracle,
+        &ctx.accounts.price_update_inf,
+        &ctx.accounts.price_update_sol,
+        sol_deposited,
+        inf_received,
+    )?;
+
+    debug_msg!("Step 5: Recording deposit");
+    let accounts = &mut *ctx.accounts;
+    record_deposit(
+        DepositAccounts {
+            user: accounts.beneficiary.key(),
+            round: &mut accounts.round,
+            user_data: &mut accounts.user_data,
+            vault_data: &mut accounts.vault_data,
+            deposit_receipt: accounts.deposit_receipt.as_mut(),
+            reward_mint: accounts.reward_mint.to_account_info(),
+            user_reward_token_account: accounts.beneficiary_reward_token_account.to_account_info(),
+            vault_authority: accounts.vault_authority.to_account_info(),
+            reward_mint_program: accounts.reward_mint_program.to_account_info(),
+            referrer_stats: accounts.referrer_stats.as_mut(),
+            referrer_reward_token_account: accounts.referrer_reward_token_account.as_ref(),
+        },
+        &accounts.config,
+        round_number,
+        sol_deposited,
+        inf_received,
+        // Sponsors cannot lock someone else's principal
+        0,
+    )?;
+
+    emit!(DepositedFor {
+        payer: accounts.payer.key(),
+        beneficiary: accounts.beneficiary.key(),
+        round_number,
+        sol_deposited,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    debug_msg!("Step 6: Deposit for beneficiary completed successfully");
+    Ok(())
+}
\ No newline at end of file
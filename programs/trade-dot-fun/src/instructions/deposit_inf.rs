@@ -0,0 +1,198 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{
+        transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked,
+    },
+};
+use pyth_solana_receiver_sdk::price_update::PriceUpdateV2;
+
+use crate::{
+    debug_msg,
+    state::{Config, DepositReceipt, Referrer, Round, UserData, VaultData},
+    errors::SwapError,
+    instructions::deposit_sol::DEPOSIT_RECEIPT_SPACE,
+    instructions::initialize_user_data::USER_DATA_SPACE,
+    utils::{check_not_paused, PAUSE_DEPOSIT},
+    utils::deposit::{check_deposit_round, get_inf_sol_value, record_deposit, DepositAccounts},
+};
+
+// Deposit of INF the user already holds, credited at the Pyth INF/SOL rate
+#[derive(Accounts)]
+#[instruction(round_number: u64)]
+pub struct InfDeposit<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        address = config.mints.inf @ SwapError::InvalidOutputMint
+    )]
+    pub inf_mint: InterfaceAccount<'info, Mint>,
+    pub inf_mint_program: Interface<'info, TokenInterface>,
+
+    #[account(
+        mut,
+        associated_token::mint = inf_mint,
+        associated_token::authority = user,
+        associated_token::token_program = inf_mint_program,
+    )]
+    pub user_inf_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        associated_token::mint = inf_mint,
+        associated_token::authority = vault_authority,
+        associated_token::token_program = inf_mint_program,
+    )]
+    pub vault_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// CHECK: This is the program's vault authority PDA
+    #[account(
+        seeds = [b"vault_authority"],
+        bump,
+    )]
+    pub vault_authority: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        address = config.mints.chip
+    )]
+    pub reward_mint: InterfaceAccount<'info, Mint>,
+
+    // Created on the user's first deposit
+    #[account(
+        init_if_needed,
+        payer = user,
+        associated_token::mint = reward_mint,
+        associated_token::authority = user,
+        associated_token::token_program = reward_mint_program,
+    )]
+    pub user_reward_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    pub reward_mint_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+
+    // Created on the user's first deposit, same layout as initialize_user_data
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = USER_DATA_SPACE,
+        seeds = [b"user_data", user.key().as_ref()],
+        bump,
+    )]
+    pub user_data: Account<'info, UserData>,
+
+    // Optional per-deposit receipt, seeded by the user's deposit counter
+    #[account(
+        init,
+        payer = user,
+        space = DEPOSIT_RECEIPT_SPACE,
+        seeds = [b"deposit_receipt", user.key().as_ref(), user_data.deposit_count.to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub deposit_receipt: Option<Account<'info, DepositReceipt>>,
+
+    #[account(
+        mut,
+        seeds = [b"vault_data"],
+        bump,
+    )]
+    pub vault_data: Account<'info, VaultData>,
+
+    #[account(
+        mut,
+        seeds = [b"round", round_number.to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub round: Account<'info, Round>,
+
+    // Referrer stats and CHIP account, required when user_data.referrer is set
+    #[account(mut)]
+    pub referrer_stats: Option<Account<'info, Referrer>>,
+
+    #[account(mut)]
+    pub referrer_reward_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    // Pyth INF/USD and SOL/USD feeds used to value the INF in SOL
+    pub price_update_inf: Account<'info, PriceUpdateV2>,
+    pub price_update_sol: Account<'info, PriceUpdateV2>,
+}
+
+pub fn deposit_inf(
+    ctx: Context<InfDeposit>,
+    round_number: u64,
+    amount: u64,
+    lock_duration: i64,
+) -> Result<()> {
+    debug_msg!("Step 1: Starting INF deposit");
+    check_not_paused(&ctx.accounts.vault_data, PAUSE_DEPOSIT)?;
+    require!(amount > 0, SwapError::ZeroAmount);
+
+    debug_msg!("Step 2: Verifying round state and number");
+    check_deposit_round(&ctx.accounts.round, round_number)?;
+
+    debug_msg!("Step 3: Valuing {} INF at the oracle rate", amount);
+    let sol_equivalent = get_inf_sol_value(
+        &ctx.accounts.config.oracle,
+        &ctx.accounts.price_update_inf,
+        &ctx.accounts.price_update_sol,
+        amount,
+        ctx.accounts.inf_mint.decimals,
+    )?;
+    require!(sol_equivalent > 0, SwapError::ZeroAmount);
+    debug_msg!("SOL equivalent: {}", sol_equivalent);
+
+    debug_msg!("Step 4: Transferring INF to the vault");
+    let vault_balance_before = ctx.accounts.vault_token_account.amount;
+    transfer_checked(
+        CpiContext::new(
+            ctx.accounts.inf_mint_program.to_account_info(),
+            TransferChecked {
+                from: ctx.accounts.user_inf_token_account.to_account_info(),
+                mint: ctx.accounts.inf_mint.to_account_info(),
+                to: ctx.accounts.vault_token_account.to_account_info(),
+                authority: ctx.accounts.user.to_account_info(),
+            },
+        ),
+        amount,
+        ctx.accounts.inf_mint.decimals,
+    )?;
+
+    ctx.accounts.vault_token_account.reload()?;
+    let inf_received = ctx.accounts.vault_token_account.amount
+        .checked_sub(vault_balance_before)
+        .unwrap();
+
+    debug_msg!("Step 5: Recording deposit");
+    let accounts = &mut *ctx.accounts;
+    record_deposit(
+        DepositAccounts {
+            user: accounts.user.key(),
+            round: &mut accounts.round,
+            user_data: &mut accounts.user_data,
+            vault_data: &mut accounts.vault_data,
+            deposit_receipt: accounts.deposit_receipt.as_mut(),
+            reward_mint: accounts.reward_mint.to_account_info(),
+            user_reward_token_account: accounts.user_reward_token_account.to_account_info(),
+            vault_authority: accounts.vault_authority.to_account_info(),
+            reward_mint_program: accounts.reward_mint_program.to_account_info(),
+            referrer_stats: accounts.referrer_stats.as_mut(),
+            referrer_reward_token_account: accounts.referrer_reward_token_account.as_ref(),
+        },
+        &accounts.config,
+        round_number,
+        sol_equivalent,
+        inf_received,
+        lock_duration,
+    )?;
+
+    debug_msg!("Step 6: INF deposit completed successfully");
+    Ok(())
+}
\ No newline at end of file
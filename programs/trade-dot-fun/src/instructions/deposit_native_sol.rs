@@ -0,0 +1,241 @@
+use anchor_lang::{
+    prelude::*,
+    solana_program::program_error::ProgramError,
+    system_program::{transfer, Transfer},
+};
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{
+        close_account, sync_native, CloseAccount, Mint, SyncNative, TokenAccount, TokenInterface,
+    },
+};
+use pyth_solana_receiver_sdk::price_update::PriceUpdateV2;
+
+use crate::{
+    debug_msg,
+    state::{Config, DepositReceipt, Referrer, Round, UserData, VaultData},
+    errors::SwapError,
+    instructions::deposit_sol::DEPOSIT_RECEIPT_SPACE,
+    instructions::initialize_user_data::USER_DATA_SPACE,
+    utils::{jupiter_program_id, check_not_paused, PAUSE_DEPOSIT},
+    utils::deposit::{check_deposit_rate, check_deposit_round, check_slippage,
+                     record_deposit, swap_into_vault, DepositAccounts},
+};
+
+// Same as UserSwap, but the WSOL comes from a temporary account wrapped and closed in this instruction
+#[derive(Accounts)]
+#[instruction(round_number: u64)]
+pub struct NativeSolSwap<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        constraint = input_mint.key() == config.mints.wsol @ SwapError::InvalidInputMint
+    )]
+    pub input_mint: InterfaceAccount<'info, Mint>,
+    pub input_mint_program: Interface<'info, TokenInterface>,
+
+    #[account(
+        constraint = output_mint.key() == config.mints.inf @ SwapError::InvalidOutputMint
+    )]
+    pub output_mint: InterfaceAccount<'info, Mint>,
+    pub output_mint_program: Interface<'info, TokenInterface>,
+
+    // Temporary WSOL account, used as the Jupiter route source in place of the user's WSOL ATA
+    #[account(
+        init,
+        payer = user,
+        seeds = [b"native_sol", user.key().as_ref()],
+        bump,
+        token::mint = input_mint,
+        token::authority = user,
+        token::token_program = input_mint_program,
+    )]
+    pub temp_wsol_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        associated_token::mint = output_mint,
+        associated_token::authority = vault_authority,
+        associated_token::token_program = output_mint_program,
+        constraint = vault_token_account.mint == config.mints.inf @ SwapError::InvalidVaultTokenAccount
+    )]
+    pub vault_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// CHECK: This is the program's vault authority PDA
+    #[account(
+        seeds = [b"vault_authority"],
+        bump,
+    )]
+    pub vault_authority: AccountInfo<'info>,
+
+    /// CHECK: This is Jupiter program with address verified
+    #[account(
+        address = jupiter_program_id() @ ProgramError::IncorrectProgramId
+    )]
+    pub jupiter_program: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        address = config.mints.chip
+    )]
+    pub reward_mint: InterfaceAccount<'info, Mint>,
+
+    // Created on the user's first deposit
+    #[account(
+        init_if_needed,
+        payer = user,
+        associated_token::mint = reward_mint,
+        associated_token::authority = user,
+        associated_token::token_program = reward_mint_program,
+    )]
+    pub user_reward_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    pub reward_mint_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+
+    // Created on the user's first deposit, same layout as initialize_user_data
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = USER_DATA_SPACE,
+        seeds = [b"user_data", user.key().as_ref()],
+        bump,
+    )]
+    pub user_data: Account<'info, UserData>,
+
+    // Optional per-deposit receipt, seeded by the user's deposit counter
+    #[account(
+        init,
+        payer = user,
+        space = DEPOSIT_RECEIPT_SPACE,
+        seeds = [b"deposit_receipt", user.key().as_ref(), user_data.deposit_count.to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub deposit_receipt: Option<Account<'info, DepositReceipt>>,
+
+    #[account(
+        mut,
+        seeds = [b"vault_data"],
+        bump,
+    )]
+    pub vault_data: Account<'info, VaultData>,
+
+    #[account(
+        mut,
+        seeds = [b"round", round_number.to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub round: Account<'info, Round>,
+
+    // Referrer stats and CHIP account, required when user_data.referrer is set
+    #[account(mut)]
+    pub referrer_stats: Option<Account<'info, Referrer>>,
+
+    #[account(mut)]
+    pub referrer_reward_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    // Pyth INF/USD and SOL/USD feeds used to sanity check the swap rate
+    pub price_update_inf: Account<'info, PriceUpdateV2>,
+    pub price_update_sol: Account<'info, PriceUpdateV2>,
+}
+
+pub fn deposit_native_sol(
+    ctx: Context<NativeSolSwap>,
+    round_number: u64,
+    amount: u64,
+    data: Vec<u8>,
+    min_inf_out: u64,
+    lock_duration: i64,
+) -> Result<()> {
+    debug_msg!("Step 1: Starting native SOL deposit");
+    check_not_paused(&ctx.accounts.vault_data, PAUSE_DEPOSIT)?;
+    require!(amount > 0, SwapError::ZeroAmount);
+
+    debug_msg!("Step 2: Verifying round state and number");
+    check_deposit_round(&ctx.accounts.round, round_number)?;
+
+    debug_msg!("Step 3: Wrapping {} lamports", amount);
+    transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.user.to_account_info(),
+                to: ctx.accounts.temp_wsol_account.to_account_info(),
+            },
+        ),
+        amount,
+    )?;
+    sync_native(CpiContext::new(
+        ctx.accounts.input_mint_program.to_account_info(),
+        SyncNative {
+            account: ctx.accounts.temp_wsol_account.to_account_info(),
+        },
+    ))?;
+
+    // Pick up the synced balance as the swap's starting point
+    ctx.accounts.temp_wsol_account.reload()?;
+
+    debug_msg!("Step 4: Executing Jupiter swap");
+    let (sol_deposited, inf_received) = swap_into_vault(
+        &ctx.accounts.jupiter_program.key(),
+        ctx.remaining_accounts,
+        data,
+        ctx.accounts.user.key(),
+        &mut ctx.accounts.temp_wsol_account,
+        &mut ctx.accounts.vault_token_account,
+    )?;
+    // The wrapped amount already caps the SOL side
+    check_slippage(sol_deposited, inf_received, amount, min_inf_out)?;
+    check_deposit_rate(
+        &ctx.accounts.config.oracle,
+        &ctx.accounts.price_update_inf,
+        &ctx.accounts.price_update_sol,
+        sol_deposited,
+        inf_received,
+    )?;
+
+    // Unswapped WSOL and the rent both go back to the user as lamports
+    debug_msg!("Step 5: Closing temporary WSOL account");
+    close_account(CpiContext::new(
+        ctx.accounts.input_mint_program.to_account_info(),
+        CloseAccount {
+            account: ctx.accounts.temp_wsol_account.to_account_info(),
+            destination: ctx.accounts.user.to_account_info(),
+            authority: ctx.accounts.user.to_account_info(),
+        },
+    ))?;
+
+    debug_msg!("Step 6: Recording deposit");
+    let accounts = &mut *ctx.accounts;
+    record_deposit(
+        DepositAccounts {
+            user: accounts.user.key(),
+            round: &mut accounts.round,
+            user_data: &mut accounts.user_data,
+            vault_data: &mut accounts.vault_data,
+            deposit_receipt: accounts.deposit_receipt.as_mut(),
+            reward_mint: accounts.reward_mint.to_account_info(),
+            user_reward_token_account: accounts.user_reward_token_account.to_account_info(),
+            vault_authority: accounts.vault_authority.to_account_info(),
+            reward_mint_program: accounts.reward_mint_program.to_account_info(),
+            referrer_stats: accounts.referrer_stats.as_mut(),
+            referrer_reward_token_account: accounts.referrer_reward_token_account.as_ref(),
+        },
+        &accounts.config,
+        round_number,
+        sol_deposited,
+        inf_received,
+        lock_duration,
+    )?;
+
+    debug_msg!("Step 7: Native SOL deposit completed successfully");
+    Ok(())
+}
\ No newline at end of file
@@ -1,15 +1,23 @@
 use anchor_lang::{
     prelude::*,
-    solana_program::{instruction::Instruction, program::invoke, program_error::ProgramError},
+    solana_program::program_error::ProgramError,
 };
-use anchor_spl::token_interface::{Mint, MintTo, TokenAccount, TokenInterface};
+use anchor_spl::{associated_token::AssociatedToken, token_interface::{Mint, TokenAccount, TokenInterface}};
+use pyth_solana_receiver_sdk::price_update::PriceUpdateV2;
 
 use crate::{
-    state::{Round, RoundState, UserData, VaultData},
-    errors::{RoundError, SwapError},
-    utils::{find_vault_authority_pda, wsol_mint, inf_mint, chip_token_mint, STAKE_RATIO, jupiter_program_id},
+    debug_msg,
+    instructions::initialize_user_data::USER_DATA_SPACE,
+    state::{Config, DepositReceipt, Referrer, Round, UserData, VaultData},
+    errors::SwapError,
+    utils::{jupiter_program_id, check_not_paused, PAUSE_DEPOSIT},
+    utils::deposit::{check_deposit_rate, check_deposit_round, check_slippage, get_input_sol_value,
+                     record_deposit, swap_into_vault, DepositAccounts},
 };
 
+// 8 (discriminator) + 32 (user) + 8 (deposit_index) + 8 (round_number) + 8 (sol_deposited) + 8 (inf_received) + 8 (exchange_rate) + 8 (chips_minted) + 8 (slot) + 8 (timestamp)
+pub const DEPOSIT_RECEIPT_SPACE: usize = 8 + 32 + 8 + 8 + 8 + 8 + 8 + 8 + 8 + 8;
+
 // Using a dynamic check for Jupiter program instead of direct import
 #[derive(Accounts)]
 #[instruction(round_number: u64)]
@@ -17,28 +25,34 @@ pub struct UserSwap<'info> {
     // User is the wallet owner and transaction signer
     #[account(mut)]
     pub user: Signer<'info>,
-    
-    // Add constraint to check input mint is WSOL
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    // Add constraint to check input mint is WSOL or an accepted LST
     #[account(
-        constraint = input_mint.key() == wsol_mint() @ SwapError::InvalidInputMint
+        constraint = input_mint.key() == config.mints.wsol
+            || config.find_accepted_lst(&input_mint.key()).is_some() @ SwapError::InvalidInputMint
     )]
     pub input_mint: InterfaceAccount<'info, Mint>,
     pub input_mint_program: Interface<'info, TokenInterface>,
     
     // Add constraint to check output mint is INF
     #[account(
-        constraint = output_mint.key() == inf_mint() @ SwapError::InvalidOutputMint
+        constraint = output_mint.key() == config.mints.inf @ SwapError::InvalidOutputMint
     )]
     pub output_mint: InterfaceAccount<'info, Mint>,
     pub output_mint_program: Interface<'info, TokenInterface>,
 
-    // Add constraint to check user's input token account is WSOL
+    // User's input token account, for the input mint checked above
     #[account(
         mut,
         associated_token::mint = input_mint,
         associated_token::authority = user,
         associated_token::token_program = input_mint_program,
-        constraint = user_input_token_account.mint == wsol_mint() @ SwapError::InvalidInputTokenAccount
     )]
     pub user_input_token_account: InterfaceAccount<'info, TokenAccount>,
 
@@ -48,7 +62,7 @@ pub struct UserSwap<'info> {
         associated_token::mint = output_mint,
         associated_token::authority = vault_authority,
         associated_token::token_program = output_mint_program,
-        constraint = vault_token_account.mint == inf_mint() @ SwapError::InvalidVaultTokenAccount
+        constraint = vault_token_account.mint == config.mints.inf @ SwapError::InvalidVaultTokenAccount
     )]
     pub vault_token_account: InterfaceAccount<'info, TokenAccount>,
 
@@ -68,12 +82,14 @@ pub struct UserSwap<'info> {
     // Add reward token accounts
     #[account(
         mut,
-        address = chip_token_mint()
+        address = config.mints.chip
     )]
     pub reward_mint: InterfaceAccount<'info, Mint>,
 
+    // Created on the user's first deposit
     #[account(
-        mut,
+        init_if_needed,
+        payer = user,
         associated_token::mint = reward_mint,
         associated_token::authority = user,
         associated_token::token_program = reward_mint_program,
@@ -82,16 +98,31 @@ pub struct UserSwap<'info> {
 
 
     pub reward_mint_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
     pub system_program: Program<'info, System>,
     
+    // Created on the user's first deposit, same layout as initialize_user_data
     #[account(
-        mut,
+        init_if_needed,
+        payer = user,
+        space = USER_DATA_SPACE,
         seeds = [b"user_data", user.key().as_ref()],
         bump,
     )]
     pub user_data: Account<'info, UserData>,
 
+    // Optional per-deposit receipt, seeded by the user's deposit counter
     #[account(
+        init,
+        payer = user,
+        space = DEPOSIT_RECEIPT_SPACE,
+        seeds = [b"deposit_receipt", user.key().as_ref(), user_data.deposit_count.to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub deposit_receipt: Option<Account<'info, DepositReceipt>>,
+
+    #[account(
+        mut,
         seeds = [b"vault_data"],
         bump,
     )]
@@ -103,177 +134,93 @@ pub struct UserSwap<'info> {
         bump,
     )]
     pub round: Account<'info, Round>,
-}
 
-pub fn deposit_sol(ctx: Context<UserSwap>, round_number: u64, data: Vec<u8>) -> Result<()> {
-    msg!("Step 1: Starting deposit function");
+    // Referrer stats and CHIP account, required when user_data.referrer is set
+    #[account(mut)]
+    pub referrer_stats: Option<Account<'info, Referrer>>,
+
+    #[account(mut)]
+    pub referrer_reward_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
 
-    // Verify round is active
-    msg!("Step 2: Verifying round state");
-    require!(
-        ctx.accounts.round.state != RoundState::Closed,
-        RoundError::InvalidRoundState
-    );
+    // Pyth price feeds used to sanity check the swap rate
+    /// CHECK: Pyth price update account for INF/USD
+    pub price_update_inf: Account<'info, PriceUpdateV2>,
 
-    // Verify round number matches
-    msg!("Step 3: Verifying round number");
-    require!(
-        ctx.accounts.round.round_number == round_number,
-        RoundError::InvalidRoundNumber
-    );
+    /// CHECK: Pyth price update account for SOL/USD
+    pub price_update_sol: Account<'info, PriceUpdateV2>,
 
-    msg!("Step 4: Getting initial balances");
-    let user_balance_before = ctx.accounts.user_input_token_account.amount;
-    let vault_balance_before = ctx.accounts.vault_token_account.amount;
+    // Pyth price feed of the input LST, required unless the input is WSOL
+    /// CHECK: Pyth price update account for the accepted LST's USD feed
+    pub price_update_lst: Option<Account<'info, PriceUpdateV2>>,
+}
 
-    msg!("Step 5: Preparing Jupiter swap accounts");
-    let accounts: Vec<AccountMeta> = ctx
-        .remaining_accounts
-        .iter()
-        .map(|acc| {
-            AccountMeta {
-                pubkey: *acc.key,
-                is_signer: acc.key == &ctx.accounts.user.key() && acc.is_signer,
-                is_writable: acc.is_writable,
-            }
-        })
-        .collect();
+pub fn deposit_sol(
+    ctx: Context<UserSwap>,
+    round_number: u64,
+    data: Vec<u8>,
+    min_inf_out: u64,
+    max_sol_in: u64,
+    lock_duration: i64,
+) -> Result<()> {
+    debug_msg!("Step 1: Starting deposit function");
+    check_not_paused(&ctx.accounts.vault_data, PAUSE_DEPOSIT)?;
 
-    let accounts_infos: Vec<AccountInfo> = ctx
-        .remaining_accounts
-        .iter()
-        .map(|acc| AccountInfo { ..acc.clone() })
-        .collect();
+    debug_msg!("Step 2: Verifying round state and number");
+    check_deposit_round(&ctx.accounts.round, round_number)?;
 
     // Execute the Jupiter swap instruction
-    msg!("Step 6: Executing Jupiter swap");
-    invoke(
-        &Instruction {
-            program_id: ctx.accounts.jupiter_program.key(),
-            accounts,
-            data,
-        },
-        &accounts_infos,
+    debug_msg!("Step 3: Executing Jupiter swap");
+    let (input_spent, inf_received) = swap_into_vault(
+        &ctx.accounts.jupiter_program.key(),
+        ctx.remaining_accounts,
+        data,
+        ctx.accounts.user.key(),
+        &mut ctx.accounts.user_input_token_account,
+        &mut ctx.accounts.vault_token_account,
     )?;
-
-    msg!("Step 7: Getting updated account balances");
-    let vault_token_account_info = &ctx.accounts.vault_token_account.to_account_info();
-    let updated_vault_token_account: TokenAccount = AccountDeserialize::try_deserialize(
-        &mut &vault_token_account_info.data.borrow()[..],
+    // max_sol_in is in input mint units
+    check_slippage(input_spent, inf_received, max_sol_in, min_inf_out)?;
+
+    // Principal is tracked in SOL, so LST inputs are valued through their Pyth feed
+    debug_msg!("Step 4: Valuing input and checking the swap rate");
+    let sol_deposited = get_input_sol_value(
+        &ctx.accounts.config,
+        &ctx.accounts.input_mint,
+        ctx.accounts.price_update_lst.as_ref(),
+        &ctx.accounts.price_update_sol,
+        input_spent,
     )?;
-
-    let user_token_account_info = &ctx.accounts.user_input_token_account.to_account_info();
-    let updated_user_token_account: TokenAccount = AccountDeserialize::try_deserialize(
-        &mut &user_token_account_info.data.borrow()[..],
+    check_deposit_rate(
+        &ctx.accounts.config.oracle,
+        &ctx.accounts.price_update_inf,
+        &ctx.accounts.price_update_sol,
+        sol_deposited,
+        inf_received,
     )?;
 
-    let vault_balance_after = updated_vault_token_account.amount;
-    let user_balance_after = updated_user_token_account.amount;
-    
-    msg!("Step 8: Verifying swap direction");
-    // Verify SOL decreased and INF increased
-    require!(
-        user_balance_after < user_balance_before,
-        SwapError::InvalidSwapDirection
-    );
-    require!(
-        vault_balance_after > vault_balance_before,
-        SwapError::InvalidSwapDirection
-    );
-
-    msg!("Step 9: Calculating differences");
-    // Calculate exact differences
-    let sol_deposited = user_balance_before
-        .checked_sub(user_balance_after)
-        .unwrap();
-    let inf_received = vault_balance_after
-        .checked_sub(vault_balance_before)
-        .unwrap();
-
-    msg!("Step 10: Updating round statistics");
-    // Update round statistics
-    ctx.accounts.round.total_sol_deposited = ctx.accounts.round.total_sol_deposited
-        .checked_add(sol_deposited)
-        .unwrap();
-    ctx.accounts.round.total_inf_received = ctx.accounts.round.total_inf_received
-        .checked_add(inf_received)
-        .unwrap();
-
-    msg!("Step 11: Updating user data");
-    // Update user data with SOL deposited and INF received
-    ctx.accounts.user_data.total_sol_deposited = ctx.accounts.user_data.total_sol_deposited
-        .checked_add(sol_deposited)
-        .unwrap();
-    ctx.accounts.user_data.total_inf_equivalent = ctx.accounts.user_data.total_inf_equivalent
-        .checked_add(inf_received)
-        .unwrap();
-
-    msg!("Step 12: Updating vault data");
-    // Update vault data
-    if ctx.accounts.vault_data.total_principal_sol == 0 {
-        // Initialize vault data if it's a new account
-        ctx.accounts.vault_data.total_principal_sol = 0;
-    }
-    
-    // Update total principal SOL
-    ctx.accounts.vault_data.total_principal_sol = ctx.accounts.vault_data.total_principal_sol
-        .checked_add(sol_deposited)
-        .unwrap();
-
-    msg!("Step 13: Calculating reward amount");
-    // Calculate reward amount (1 reward token per 0.001 SOL)
-    let reward_amount = sol_deposited
-        .checked_div(STAKE_RATIO)
-        .unwrap();
-
-    msg!("Step 14: Preparing PDA signer");
-    // Get PDA signer seeds for minting
-    let (_, bump) = find_vault_authority_pda();
-    let vault_authority_seeds = &[b"vault_authority".as_ref(), &[bump]];
-    let signer_seeds = &[&vault_authority_seeds[..]];
-
-    msg!("Step 15: Minting reward tokens");
-    // Mint reward tokens to the user
-    let cpi_accounts = MintTo {
-        mint: ctx.accounts.reward_mint.to_account_info(),
-        to: ctx.accounts.user_reward_token_account.to_account_info(),
-        authority: ctx.accounts.vault_authority.to_account_info(),
-    };
-
-    let cpi_program = ctx.accounts.reward_mint_program.to_account_info();
-    let cpi_ctx = CpiContext::new_with_signer(
-        cpi_program,
-        cpi_accounts,
-        signer_seeds
-    );
-    
-    anchor_spl::token_interface::mint_to(cpi_ctx, reward_amount)?;
-
-    msg!("Step 16: Logging transaction details");
-    // Log all the changes
-    msg!("SOL decreased by: {}", sol_deposited);
-    msg!("INF increased by: {}", inf_received);
-    msg!("Reward tokens minted: {} (1 token per 0.001 SOL)", reward_amount);
-    msg!("Total vault principal SOL: {}", ctx.accounts.vault_data.total_principal_sol);
-
-    // Calculate and log exchange rate (INF per SOL)
-    msg!("Exchange rate: {} INF per SOL", inf_received
-        .checked_mul(1_000_000) // Scale by 10^6 for precision
-        .and_then(|x| x.checked_div(sol_deposited))
-        .unwrap_or(0));
-
-    // Log round statistics
-    msg!("Round {} statistics:", ctx.accounts.round.round_number);
-    msg!("Total SOL deposited: {}", ctx.accounts.round.total_sol_deposited);
-    msg!("Total INF received: {}", ctx.accounts.round.total_inf_received);
-
-
-    msg!(
-        "User participated: user={} round={}",
-        ctx.accounts.user.key(),
-        ctx.accounts.round.round_number,
-    );
+    debug_msg!("Step 5: Recording deposit");
+    let accounts = &mut *ctx.accounts;
+    record_deposit(
+        DepositAccounts {
+            user: accounts.user.key(),
+            round: &mut accounts.round,
+            user_data: &mut accounts.user_data,
+            vault_data: &mut accounts.vault_data,
+            deposit_receipt: accounts.deposit_receipt.as_mut(),
+            reward_mint: accounts.reward_mint.to_account_info(),
+            user_reward_token_account: accounts.user_reward_token_account.to_account_info(),
+            vault_authority: accounts.vault_authority.to_account_info(),
+            reward_mint_program: accounts.reward_mint_program.to_account_info(),
+            referrer_stats: accounts.referrer_stats.as_mut(),
+            referrer_reward_token_account: accounts.referrer_reward_token_account.as_ref(),
+        },
+        &accounts.config,
+        round_number,
+        sol_deposited,
+        inf_received,
+        lock_duration,
+    )?;
 
-    msg!("Step 17: Deposit function completed successfully");
+    debug_msg!("Step 6: Deposit function completed successfully");
     Ok(())
-} 
\ No newline at end of file
+}
\ No newline at end of file
@@ -4,13 +4,16 @@ use anchor_spl::{
     associated_token::get_associated_token_address,
     token_interface::{Mint, TokenAccount, TokenInterface, Transfer, Burn}
 };
-use pyth_solana_receiver_sdk::price_update::{get_feed_id_from_hex, PriceUpdateV2};
+use pyth_solana_receiver_sdk::price_update::PriceUpdateV2;
 
 use crate::{
-    state::{Round, RoundState, VaultData},
+    debug_msg,
+    state::{Config, Role, RoleRegistry, Round, RoundState, VaultData},
     errors::RoundError,
-    utils::{inf_mint, find_vault_authority_pda, find_round_pda, chip_token_mint, 
-            INF_USD_PRICE_FEED_ID, SOL_USD_PRICE_FEED_ID, MAXIMUM_AGE, check_authorized_admin},
+    events::{ChipsBurned, RewardDistributed},
+    utils::{find_vault_authority_pda, find_round_pda, 
+            get_checked_price, check_role,
+            check_not_paused, PAUSE_DISTRIBUTE_REWARD},
 };
 
 #[derive(Accounts)]
@@ -19,6 +22,18 @@ pub struct DistributeReward<'info> {
     #[account(mut)]
     pub authority: Signer<'info>,
 
+    #[account(
+        seeds = [b"role_registry"],
+        bump = role_registry.bump,
+    )]
+    pub role_registry: Account<'info, RoleRegistry>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, Config>,
+
     #[account(
         mut,
         seeds = [b"round", round_number.to_le_bytes().as_ref()],
@@ -43,7 +58,7 @@ pub struct DistributeReward<'info> {
 
     #[account(
         mut,
-        address = chip_token_mint()
+        address = config.mints.chip
     )]
     pub reward_mint: InterfaceAccount<'info, Mint>,
 
@@ -62,6 +77,9 @@ pub struct DistributeReward<'info> {
     )]
     pub vault_authority: AccountInfo<'info>,
 
+    #[account(
+        address = config.mints.inf
+    )]
     pub inf_mint: InterfaceAccount<'info, Mint>,
     pub inf_mint_program: Interface<'info, TokenInterface>,
     pub reward_mint_program: Interface<'info, TokenInterface>,
@@ -80,8 +98,9 @@ pub fn distribute_reward<'info>(
     winner_addresses: Vec<Pubkey>,
     winner_ratios: Vec<u64>,
 ) -> Result<()> {
-    // Check that the authority is the authorized admin
-    check_authorized_admin(&ctx.accounts.authority.key())?;
+    // Check that the authority holds the distributor role
+    check_role(&ctx.accounts.authority.key(), &ctx.accounts.role_registry, Role::Distributor)?;
+    check_not_paused(&ctx.accounts.vault_data, PAUSE_DISTRIBUTE_REWARD)?;
     
     // Verify round is active
     require!(
@@ -97,35 +116,36 @@ pub fn distribute_reward<'info>(
 
     
     // Step 1: Verify arrays have same length and total ratio is 100%
-    msg!("Step 1: Verifying winner data");
+    debug_msg!("Step 1: Verifying winner data");
     require!(
         winner_addresses.len() == winner_ratios.len(),
         RoundError::InvalidWinnerData
     );
 
     // Step 2: Get current INF/USD price from Pyth
-    msg!("Step 2: Getting INF/USD price");
-    let inf_price_update = &ctx.accounts.price_update_inf;
-    let inf_price_data = inf_price_update.get_price_no_older_than(
-        &Clock::get()?,
-        MAXIMUM_AGE,
-        &get_feed_id_from_hex(INF_USD_PRICE_FEED_ID)?,
+    debug_msg!("Step 2: Getting INF/USD price");
+    let oracle = &ctx.accounts.config.oracle;
+    let inf_price_data = get_checked_price(
+        &ctx.accounts.price_update_inf,
+        &oracle.inf_usd_feed_id,
+        oracle.inf_max_age,
+        oracle.max_conf_ratio_bps,
     )?;
 
     // Step 3: Get current SOL/USD price from Pyth
-    msg!("Step 3: Getting SOL/USD price");
-    let sol_price_update = &ctx.accounts.price_update_sol;
-    let sol_price_data = sol_price_update.get_price_no_older_than(
-        &Clock::get()?,
-        MAXIMUM_AGE,
-        &get_feed_id_from_hex(SOL_USD_PRICE_FEED_ID)?,
+    debug_msg!("Step 3: Getting SOL/USD price");
+    let sol_price_data = get_checked_price(
+        &ctx.accounts.price_update_sol,
+        &oracle.sol_usd_feed_id,
+        oracle.sol_max_age,
+        oracle.max_conf_ratio_bps,
     )?;
 
-    msg!("INF/USD price data: {:?}", inf_price_data);
-    msg!("SOL/USD price data: {:?}", sol_price_data);
+    debug_msg!("INF/USD price data: {:?}", inf_price_data);
+    debug_msg!("SOL/USD price data: {:?}", sol_price_data);
     
     // Step 4: Calculate INF/SOL price ratio
-    msg!("Step 4: Calculating INF/SOL price ratio");
+    debug_msg!("Step 4: Calculating INF/SOL price ratio");
     let inf_price_raw = inf_price_data.price as u64;
     let sol_price_raw = sol_price_data.price as u64;
     
@@ -135,17 +155,17 @@ pub fn distribute_reward<'info>(
         .and_then(|x| x.checked_div(sol_price_raw))
         .ok_or(RoundError::InvalidPriceData)?;
 
-    msg!("Calculated INF/SOL price (scaled by 10^6): {}", current_inf_sol_price);
+    debug_msg!("Calculated INF/SOL price (scaled by 10^6): {}", current_inf_sol_price);
 
     // Step 5: Calculate initial INF price from round data
-    msg!("Step 5: Calculating initial INF price");
+    debug_msg!("Step 5: Calculating initial INF price");
     let initial_inf_price = ctx.accounts.round.total_inf_received
         .checked_mul(scale_factor)
         .and_then(|x| x.checked_div(ctx.accounts.round.total_sol_deposited))
         .ok_or(RoundError::InvalidPriceData)?;
 
     // Step 6: Calculate price increase percentage
-    msg!("Step 6: Calculating price increase percentage");
+    debug_msg!("Step 6: Calculating price increase percentage");
     let price_increase = current_inf_sol_price
         .checked_sub(initial_inf_price)
         .and_then(|x| x.checked_mul(100))
@@ -153,7 +173,7 @@ pub fn distribute_reward<'info>(
         .ok_or(RoundError::InvalidPriceData)?;
 
     // Step 7: Calculate total INF to distribute (interest only)
-    msg!("Step 7: Calculating INF distribution amount");
+    debug_msg!("Step 7: Calculating INF distribution amount");
     let total_inf = ctx.accounts.vault_token_account.amount;
     let principal_inf = total_inf
         .checked_mul(100)
@@ -165,7 +185,7 @@ pub fn distribute_reward<'info>(
         .ok_or(RoundError::InvalidPriceData)?;
 
     // Step 8: Calculate reward amount per token
-    msg!("Step 8: Calculating reward per token");
+    debug_msg!("Step 8: Calculating reward per token");
     let round_participation_tokens = ctx.accounts.round_reward_token_account.amount;
     require!(round_participation_tokens > 0, RoundError::NoRewardsToDistribute);
 
@@ -177,12 +197,12 @@ pub fn distribute_reward<'info>(
         .ok_or(RoundError::InvalidPriceData)?;
 
     
-    msg!("Total INF in vault: {}", total_inf);
-    msg!("Total reward amount to distribute: {}", reward_amount);
+    debug_msg!("Total INF in vault: {}", total_inf);
+    debug_msg!("Total reward amount to distribute: {}", reward_amount);
 
 
     // Step 9: Verify total reward ratio is 100%
-    msg!("Step 9: Verifying total reward ratio");
+    debug_msg!("Step 9: Verifying total reward ratio");
     let total_ratio: u64 = winner_ratios.iter().sum();
     require!(
         total_ratio == 100,
@@ -190,7 +210,7 @@ pub fn distribute_reward<'info>(
     );
     
     // Step 10: Verify remaining accounts
-    msg!("Step 10: Verifying remaining accounts");
+    debug_msg!("Step 10: Verifying remaining accounts");
     require!(
         ctx.remaining_accounts.len() == winner_addresses.len(),
         RoundError::InvalidRemainingAccounts
@@ -198,13 +218,13 @@ pub fn distribute_reward<'info>(
     
 
     // Step 11: Get PDA signer seeds for transfers
-    msg!("Step 11: Getting PDA signer seeds");
+    debug_msg!("Step 11: Getting PDA signer seeds");
     let (_, bump) = find_vault_authority_pda();
     let vault_authority_seeds = &[b"vault_authority".as_ref(), &[bump]];
     let signer_seeds = &[&vault_authority_seeds[..]];
         
     // Step 12: Distribute INF to winners
-    msg!("Step 12: Distributing INF to winners");
+    debug_msg!("Step 12: Distributing INF to winners");
     
     for i in 0..winner_addresses.len() {
         let winner_address = &winner_addresses[i];
@@ -220,7 +240,7 @@ pub fn distribute_reward<'info>(
             // Verify this token account belongs to the winner by checking its data
             let expected_token_address = get_associated_token_address(
                 winner_address,
-                &inf_mint()
+                &ctx.accounts.config.mints.inf
             );
             
             require!(
@@ -228,7 +248,7 @@ pub fn distribute_reward<'info>(
                 RoundError::InvalidTokenAccount
             );
             
-            msg!("Winner {} share: {} INF", winner_address, winner_share);
+            debug_msg!("Winner {} share: {} INF", winner_address, winner_share);
             
             let from_info = ctx.accounts.vault_token_account.to_account_info();
             let authority_info = ctx.accounts.vault_authority.to_account_info();
@@ -243,11 +263,21 @@ pub fn distribute_reward<'info>(
             let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds);
             
             anchor_spl::token_interface::transfer(cpi_ctx, winner_share)?;
+
+            emit!(RewardDistributed {
+                round_number,
+                winner: *winner_address,
+                mint: ctx.accounts.inf_mint.key(),
+                ratio,
+                share: winner_share,
+                total_reward: reward_amount,
+                timestamp: Clock::get()?.unix_timestamp,
+            });
         }
     }
 
     // Step 13: Burning reward tokens
-    msg!("Step 13: Burning reward tokens");
+    debug_msg!("Step 13: Burning reward tokens");
     let burn_amount = ctx.accounts.round_reward_token_account.amount;
     if burn_amount > 0 {
         // Get round PDA signer seeds for burn authorization
@@ -270,17 +300,31 @@ pub fn distribute_reward<'info>(
         );
         
         anchor_spl::token_interface::burn(cpi_ctx, burn_amount)?;
+
+        emit!(ChipsBurned {
+            round_number,
+            amount: burn_amount,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
     }
 
     // Step 14: Update round state and vault data
-    msg!("Step 14: Updating round state and vault data");
+    debug_msg!("Step 14: Updating round state and vault data");
     ctx.accounts.round.state = RoundState::Closed;
     ctx.accounts.round.end_time = Clock::get()?.unix_timestamp;
 
-    msg!("Rewards distributed for round {}", round_number);
-    msg!("Total INF distributed: {}", reward_amount);
-    msg!("Total chips burned: {}", burn_amount);
-    msg!("Reward per token: {}", reward_amount);
-    msg!("SOL distributed for Round {}", round_number);
+    debug_msg!("Rewards distributed for round {}", round_number);
+    debug_msg!("Total INF distributed: {}", reward_amount);
+    debug_msg!("Total chips burned: {}", burn_amount);
+    debug_msg!("Reward per token: {}", reward_amount);
+    debug_msg!("SOL distributed for Round {}", round_number);
+
+    msg!(
+        "Distribute: round={} inf={} winners={} burned={}",
+        round_number,
+        reward_amount,
+        winner_addresses.len(),
+        burn_amount,
+    );
     Ok(())
 }
\ No newline at end of file
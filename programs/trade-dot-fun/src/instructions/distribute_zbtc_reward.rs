@@ -1,6 +1,6 @@
 use anchor_lang::{
     prelude::*,
-    solana_program::{instruction::Instruction, program::invoke, program_error::ProgramError},
+    solana_program::program_error::ProgramError,
 };
 use anchor_spl::{
     associated_token::AssociatedToken, 
@@ -10,11 +10,14 @@ use anchor_spl::{
 use pyth_solana_receiver_sdk::price_update::PriceUpdateV2;
 
 use crate::{
-    state::{Round, RoundState, VaultData},
+    debug_msg,
+    state::{Config, Role, RoleRegistry, Round, RoundState, VaultData},
     errors::RoundError,
-    utils::{ zbtc_mint, find_vault_authority_pda, find_round_pda, chip_token_mint, 
-             check_authorized_admin,
+    events::{ChipsBurned, RewardDistributed},
+    utils::{ find_vault_authority_pda, find_round_pda, 
+             check_role, check_not_paused, PAUSE_DISTRIBUTE_ZBTC_REWARD,
             jupiter_program_id},
+    utils::jupiter::{invoke_jupiter_swap, ExpectedRoute},
 };
 
 #[derive(Accounts)]
@@ -23,6 +26,18 @@ pub struct DistributeZbtcReward<'info> {
     #[account(mut)]
     pub authority: Signer<'info>,
 
+    #[account(
+        seeds = [b"role_registry"],
+        bump = role_registry.bump,
+    )]
+    pub role_registry: Account<'info, RoleRegistry>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, Config>,
+
     #[account(
         mut,
         seeds = [b"round", round_number.to_le_bytes().as_ref()],
@@ -57,7 +72,7 @@ pub struct DistributeZbtcReward<'info> {
 
     #[account(
         mut,
-        address = chip_token_mint()
+        address = config.mints.chip
     )]
     pub reward_mint: InterfaceAccount<'info, Mint>,
 
@@ -76,9 +91,15 @@ pub struct DistributeZbtcReward<'info> {
     )]
     pub vault_authority: AccountInfo<'info>,
 
+    #[account(
+        address = config.mints.inf
+    )]
     pub inf_mint: InterfaceAccount<'info, Mint>,
     pub inf_mint_program: Interface<'info, TokenInterface>,
     
+    #[account(
+        address = config.mints.zbtc
+    )]
     pub zbtc_mint: InterfaceAccount<'info, Mint>,
     pub zbtc_mint_program: Interface<'info, TokenInterface>,
     
@@ -105,8 +126,9 @@ pub fn distribute_zbtc_reward<'info>(
     winner_ratios: Vec<u64>,
     jupiter_swap_data: Vec<u8>, // Jupiter swap instruction data
 ) -> Result<()> {
-    // Check that the authority is the authorized admin
-    check_authorized_admin(&ctx.accounts.authority.key())?;
+    // Check that the authority holds the distributor role
+    check_role(&ctx.accounts.authority.key(), &ctx.accounts.role_registry, Role::Distributor)?;
+    check_not_paused(&ctx.accounts.vault_data, PAUSE_DISTRIBUTE_ZBTC_REWARD)?;
     
     // Verify round is active
     require!(
@@ -121,14 +143,14 @@ pub fn distribute_zbtc_reward<'info>(
     );
 
     // Step 1: Verify arrays have same length and total ratio is 100%
-    msg!("Step 1: Verifying winner data");
+    debug_msg!("Step 1: Verifying winner data");
     require!(
         winner_addresses.len() == winner_ratios.len(),
         RoundError::InvalidWinnerData
     );
 
     // Step 2: Verify total reward ratio is 100%
-    msg!("Step 2: Verifying total reward ratio");
+    debug_msg!("Step 2: Verifying total reward ratio");
     let total_ratio: u64 = winner_ratios.iter().sum();
     require!(
         total_ratio == 100,
@@ -136,7 +158,7 @@ pub fn distribute_zbtc_reward<'info>(
     );
 
     // Step 3: Verify INF availability in vault 
-    msg!("Step 3: Verifying INF availability in vault");
+    debug_msg!("Step 3: Verifying INF availability in vault");
     let vault_inf_balance_before = ctx.accounts.vault_inf_token_account.amount;
     require!(
         vault_inf_balance_before > 0,
@@ -144,45 +166,36 @@ pub fn distribute_zbtc_reward<'info>(
     );
     
     // Step 4: Get ZBTC balance before swap
-    msg!("Step 4: Getting ZBTC balance before swap");
+    debug_msg!("Step 4: Getting ZBTC balance before swap");
     let vault_zbtc_balance_before = ctx.accounts.vault_zbtc_token_account.amount;
     
-    // Step 5: Prepare Jupiter swap
-    msg!("Step 5: Preparing Jupiter swap");
-    let accounts: Vec<AccountMeta> = ctx
-        .remaining_accounts
-        .iter()
-        .map(|acc| {
-            AccountMeta {
-                pubkey: *acc.key,
-                is_signer: acc.key == &ctx.accounts.authority.key() && acc.is_signer,
-                is_writable: acc.is_writable,
-            }
-        })
-        .collect();
-
-    // Store the accounts length before moving accounts vector
-    let accounts_len = accounts.len();
-
-    let accounts_infos: Vec<AccountInfo> = ctx
-        .remaining_accounts
-        .iter()
-        .map(|acc| AccountInfo { ..acc.clone() })
-        .collect();
+    // Step 5: Split remaining accounts into the Jupiter route and the winner token accounts
+    debug_msg!("Step 5: Preparing Jupiter swap");
+    require!(
+        ctx.remaining_accounts.len() > winner_addresses.len(),
+        RoundError::InvalidRemainingAccounts
+    );
+    let accounts_len = ctx.remaining_accounts.len() - winner_addresses.len();
+    let (_, bump) = find_vault_authority_pda();
+    let vault_authority_seeds = &[b"vault_authority".as_ref(), &[bump]];
+    let signer_seeds = &[&vault_authority_seeds[..]];
 
-    // Step 6: Execute Jupiter swap to convert INF to ZBTC
-    msg!("Step 6: Executing Jupiter swap from INF to ZBTC");
-    invoke(
-        &Instruction {
-            program_id: ctx.accounts.jupiter_program.key(),
-            accounts,
-            data: jupiter_swap_data,
+    // Step 6: Execute Jupiter swap to convert INF to ZBTC, signed by the vault authority
+    debug_msg!("Step 6: Executing Jupiter swap from INF to ZBTC");
+    invoke_jupiter_swap(
+        &ctx.accounts.jupiter_program.key(),
+        &ctx.remaining_accounts[..accounts_len],
+        jupiter_swap_data,
+        &ExpectedRoute {
+            authority: ctx.accounts.vault_authority.key(),
+            source: ctx.accounts.vault_inf_token_account.key(),
+            destination: ctx.accounts.vault_zbtc_token_account.key(),
         },
-        &accounts_infos,
+        signer_seeds,
     )?;
 
     // Step 7: Calculate the amount of ZBTC received
-    msg!("Step 7: Calculating ZBTC received from swap");
+    debug_msg!("Step 7: Calculating ZBTC received from swap");
     let vault_zbtc_account_info = &ctx.accounts.vault_zbtc_token_account.to_account_info();
     let updated_vault_zbtc_account: TokenAccount = AccountDeserialize::try_deserialize(
         &mut &vault_zbtc_account_info.data.borrow()[..],
@@ -193,26 +206,20 @@ pub fn distribute_zbtc_reward<'info>(
         .checked_sub(vault_zbtc_balance_before)
         .ok_or(RoundError::InvalidPriceData)?;
     
-    msg!("ZBTC received from swap: {}", zbtc_received);
+    debug_msg!("ZBTC received from swap: {}", zbtc_received);
     
     // Step 8: Verify winner remaining accounts
-    msg!("Step 8: Verifying remaining accounts for winners");
-    // We've already used the first N remaining accounts for Jupiter swap
-    // The next batch is for the winner token accounts
+    debug_msg!("Step 8: Verifying remaining accounts for winners");
+    // The first accounts_len remaining accounts were the Jupiter route
+    // The rest are the winner token accounts
     let winner_accounts_start_index = accounts_len;
     require!(
         ctx.remaining_accounts.len() >= winner_accounts_start_index + winner_addresses.len(),
         RoundError::InvalidRemainingAccounts
     );
     
-    // Step 9: Get PDA signer seeds for transfers
-    msg!("Step 9: Getting PDA signer seeds");
-    let (_, bump) = find_vault_authority_pda();
-    let vault_authority_seeds = &[b"vault_authority".as_ref(), &[bump]];
-    let signer_seeds = &[&vault_authority_seeds[..]];
-        
-    // Step 10: Distribute ZBTC to winners
-    msg!("Step 10: Distributing ZBTC to winners");
+    // Step 9: Distribute ZBTC to winners
+    debug_msg!("Step 9: Distributing ZBTC to winners");
     
     for i in 0..winner_addresses.len() {
         let winner_address = &winner_addresses[i];
@@ -228,7 +235,7 @@ pub fn distribute_zbtc_reward<'info>(
             // Verify this token account belongs to the winner by checking its data
             let expected_token_address = get_associated_token_address(
                 winner_address,
-                &zbtc_mint()
+                &ctx.accounts.config.mints.zbtc
             );
             
             require!(
@@ -236,7 +243,7 @@ pub fn distribute_zbtc_reward<'info>(
                 RoundError::InvalidTokenAccount
             );
             
-            msg!("Winner {} share: {} ZBTC", winner_address, winner_share);
+            debug_msg!("Winner {} share: {} ZBTC", winner_address, winner_share);
             
             let from_info = ctx.accounts.vault_zbtc_token_account.to_account_info();
             let authority_info = ctx.accounts.vault_authority.to_account_info();
@@ -251,11 +258,21 @@ pub fn distribute_zbtc_reward<'info>(
             let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds);
             
             anchor_spl::token_interface::transfer(cpi_ctx, winner_share)?;
+
+            emit!(RewardDistributed {
+                round_number,
+                winner: *winner_address,
+                mint: ctx.accounts.zbtc_mint.key(),
+                ratio,
+                share: winner_share,
+                total_reward: zbtc_received,
+                timestamp: Clock::get()?.unix_timestamp,
+            });
         }
     }
 
-    // Step 11: Burning reward tokens
-    msg!("Step 11: Burning reward tokens");
+    // Step 10: Burning reward tokens
+    debug_msg!("Step 10: Burning reward tokens");
     
     let round_key = ctx.accounts.round.key();
     let (_round_pda, _round_bump) = find_round_pda(round_number);
@@ -281,7 +298,21 @@ pub fn distribute_zbtc_reward<'info>(
     
     let round_participation_tokens = ctx.accounts.round_reward_token_account.amount;
     anchor_spl::token_interface::burn(cpi_ctx, round_participation_tokens)?;
+
+    emit!(ChipsBurned {
+        round_number,
+        amount: round_participation_tokens,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
     
-    msg!("ZBTC rewards distributed successfully");
+    debug_msg!("ZBTC rewards distributed successfully");
+
+    msg!(
+        "Distribute zBTC: round={} zbtc={} winners={} burned={}",
+        round_number,
+        zbtc_received,
+        winner_addresses.len(),
+        round_participation_tokens,
+    );
     Ok(())
 } 
\ No newline at end of file
@@ -0,0 +1,94 @@
+use anchor_lang::prelude::*;
+use crate::{
+    state::{AdminAction, Config, PendingAction},
+    errors::{ConfigError, TimelockError},
+    events::ActionExecuted,
+    utils::{validate_accepted_lst, MAX_ACCEPTED_LSTS},
+};
+
+#[derive(Accounts)]
+#[instruction(action_id: u64)]
+pub struct ExecuteAction<'info> {
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        seeds = [b"pending_action", action_id.to_le_bytes().as_ref()],
+        bump = pending_action.bump,
+        has_one = proposer,
+        close = proposer,
+    )]
+    pub pending_action: Account<'info, PendingAction>,
+
+    /// CHECK: Rent receiver, checked against pending_action.proposer
+    #[account(mut)]
+    pub proposer: AccountInfo<'info>,
+
+    // Anyone can execute an action once its timelock has expired
+    pub executor: Signer<'info>,
+}
+
+pub fn execute_action(ctx: Context<ExecuteAction>, action_id: u64) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let pending_action = &ctx.accounts.pending_action;
+    require!(now >= pending_action.eta, TimelockError::TimelockNotExpired);
+
+    let config = &mut ctx.accounts.config;
+    match pending_action.action {
+        AdminAction::ProposeAdmin { new_admin } => {
+            // The handover only completes once the new admin calls accept_admin
+            config.pending_admin = Some(new_admin);
+        }
+        AdminAction::SetTimelockDelay { delay } => {
+            config.timelock_delay = delay;
+        }
+        AdminAction::SetMints { mints } => {
+            config.mints = mints;
+        }
+        AdminAction::SetOracleConfig { oracle } => {
+            config.oracle = oracle;
+        }
+        AdminAction::AddAcceptedLst { lst } => {
+            // Re-checked here since the list may have changed while the action was queued
+            validate_accepted_lst(&lst, config)?;
+            require!(
+                config.find_accepted_lst(&lst.mint).is_none(),
+                ConfigError::LstAlreadyAccepted
+            );
+            require!(
+                config.accepted_lsts.len() < MAX_ACCEPTED_LSTS,
+                ConfigError::AcceptedLstsFull
+            );
+            config.accepted_lsts.push(lst);
+        }
+        AdminAction::SetReferralBonus { bps } => {
+            config.referral_bonus_bps = bps;
+        }
+        AdminAction::SetLockTiers { ref tiers } => {
+            // Only applies to new deposits, existing locks keep their expiry
+            config.lock_tiers = tiers.clone();
+        }
+        AdminAction::RemoveAcceptedLst { mint } => {
+            let index = config.accepted_lsts
+                .iter()
+                .position(|lst| lst.mint == mint)
+                .ok_or(ConfigError::LstNotAccepted)?;
+            config.accepted_lsts.remove(index);
+        }
+    }
+
+    emit!(ActionExecuted {
+        action_id,
+        action: pending_action.action.clone(),
+        executor: ctx.accounts.executor.key(),
+        timestamp: now,
+    });
+
+    msg!("Action {} executed", action_id);
+    Ok(())
+}
@@ -0,0 +1,179 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{
+    transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked,
+};
+use pyth_solana_receiver_sdk::price_update::PriceUpdateV2;
+
+use crate::{
+    debug_msg,
+    state::{Config, Round, RoundState, UserData, VaultData, WithdrawalTicket},
+    errors::{RedeemError, WithdrawalError},
+    events::{Redeemed, WithdrawalFulfilled},
+    utils::{find_vault_authority_pda, check_not_paused, PAUSE_REDEEM},
+    utils::redeem::{apply_redeem, RedeemAmounts},
+};
+
+// Permissionless: anyone can pay out a ticket once its round has closed
+#[derive(Accounts)]
+pub struct FulfillWithdrawal<'info> {
+    pub fulfiller: Signer<'info>,
+
+    /// CHECK: Owner of the ticket, receives the INF and the ticket rent
+    #[account(mut)]
+    pub user: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        close = user,
+        seeds = [b"withdrawal_ticket", user.key().as_ref()],
+        bump = withdrawal_ticket.bump,
+        has_one = user,
+    )]
+    pub withdrawal_ticket: Account<'info, WithdrawalTicket>,
+
+    #[account(
+        seeds = [b"round", withdrawal_ticket.round_number.to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub round: Account<'info, Round>,
+
+    #[account(
+        mut,
+        seeds = [b"user_data", user.key().as_ref()],
+        bump,
+    )]
+    pub user_data: Account<'info, UserData>,
+
+    #[account(
+        mut,
+        seeds = [b"vault_data"],
+        bump,
+    )]
+    pub vault_data: Account<'info, VaultData>,
+
+    #[account(
+        address = config.mints.inf
+    )]
+    pub token_mint: InterfaceAccount<'info, Mint>,
+    pub token_program: Interface<'info, TokenInterface>,
+
+    #[account(
+        mut,
+        associated_token::mint = token_mint,
+        associated_token::authority = user,
+        associated_token::token_program = token_program,
+    )]
+    pub user_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        associated_token::mint = token_mint,
+        associated_token::authority = vault_authority,
+        associated_token::token_program = token_program,
+    )]
+    pub vault_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// CHECK: This is the program's vault authority PDA
+    #[account(
+        seeds = [b"vault_authority"],
+        bump,
+    )]
+    pub vault_authority: AccountInfo<'info>,
+
+    // Pyth price feeds for getting current exchange rate
+    /// CHECK: Pyth price update account for INF/USD
+    pub price_update_inf: Account<'info, PriceUpdateV2>,
+
+    /// CHECK: Pyth price update account for SOL/USD
+    pub price_update_sol: Account<'info, PriceUpdateV2>,
+}
+
+pub fn fulfill_withdrawal(ctx: Context<FulfillWithdrawal>) -> Result<()> {
+    check_not_paused(&ctx.accounts.vault_data, PAUSE_REDEEM)?;
+
+    // Principal leaves the vault only between rounds, as with redeem
+    let is_round_active = ctx.accounts.vault_data.is_round_active;
+    require!(!is_round_active, RedeemError::RoundActive);
+
+    // Step 1: The ticket's round must be over
+    debug_msg!("Step 1: Checking round {} is closed", ctx.accounts.round.round_number);
+    require!(
+        ctx.accounts.round.state == RoundState::Closed,
+        WithdrawalError::RoundNotClosed
+    );
+
+    // Step 2: Redeem the ticket's principal like redeem would
+    let sol_amount = ctx.accounts.withdrawal_ticket.sol_amount;
+    debug_msg!("Step 2: Applying redemption of {}", sol_amount);
+    let RedeemAmounts {
+        sol_principal,
+        base_inf_amount,
+        inf_to_redeem,
+        initial_exchange_rate,
+        current_exchange_rate,
+    } = apply_redeem(
+        &ctx.accounts.config.oracle,
+        &mut ctx.accounts.user_data,
+        &mut ctx.accounts.vault_data,
+        &ctx.accounts.price_update_inf,
+        &ctx.accounts.price_update_sol,
+        sol_amount,
+        ctx.accounts.vault_token_account.amount,
+    )?;
+
+    // Step 3: Transfer INF tokens from vault to user
+    debug_msg!("Step 3: Transferring {} INF tokens to user", inf_to_redeem);
+    let (_, bump) = find_vault_authority_pda();
+    let vault_authority_seeds = &[b"vault_authority".as_ref(), &[bump]];
+    let signer_seeds = &[&vault_authority_seeds[..]];
+    transfer_checked(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            TransferChecked {
+                from: ctx.accounts.vault_token_account.to_account_info(),
+                mint: ctx.accounts.token_mint.to_account_info(),
+                to: ctx.accounts.user_token_account.to_account_info(),
+                authority: ctx.accounts.vault_authority.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        inf_to_redeem,
+        ctx.accounts.token_mint.decimals,
+    )?;
+
+    msg!(
+        "Withdrawal fulfilled: user={} sol={} inf={} rate={}",
+        ctx.accounts.user.key(),
+        sol_principal,
+        inf_to_redeem,
+        current_exchange_rate,
+    );
+
+    let timestamp = Clock::get()?.unix_timestamp;
+    emit!(Redeemed {
+        user: ctx.accounts.user.key(),
+        sol_principal,
+        base_inf_amount,
+        inf_redeemed: inf_to_redeem,
+        initial_exchange_rate,
+        current_exchange_rate,
+        remaining_principal_sol: ctx.accounts.user_data.total_sol_deposited,
+        total_principal_sol: ctx.accounts.vault_data.total_principal_sol,
+        timestamp,
+    });
+    emit!(WithdrawalFulfilled {
+        user: ctx.accounts.user.key(),
+        fulfiller: ctx.accounts.fulfiller.key(),
+        sol_amount: sol_principal,
+        inf_redeemed: inf_to_redeem,
+        timestamp,
+    });
+
+    Ok(())
+}
\ No newline at end of file
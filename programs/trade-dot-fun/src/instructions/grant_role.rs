@@ -0,0 +1,57 @@
+use anchor_lang::prelude::*;
+use crate::{
+    state::{Config, Role, RoleMember, RoleRegistry},
+    errors::RoleError,
+    events::RoleGranted,
+    utils::{check_authorized_admin, MAX_ROLE_MEMBERS},
+};
+
+#[derive(Accounts)]
+pub struct GrantRole<'info> {
+    #[account(
+        mut,
+        seeds = [b"role_registry"],
+        bump = role_registry.bump,
+    )]
+    pub role_registry: Account<'info, RoleRegistry>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    pub authority: Signer<'info>,
+}
+
+pub fn grant_role(ctx: Context<GrantRole>, role: Role, member: Pubkey) -> Result<()> {
+    // Check that the authority is the authorized admin
+    check_authorized_admin(&ctx.accounts.authority.key(), &ctx.accounts.config)?;
+
+    let role_registry = &mut ctx.accounts.role_registry;
+    match role_registry.members.iter_mut().find(|m| m.member == member) {
+        Some(entry) => {
+            require!(entry.roles & role.mask() == 0, RoleError::RoleAlreadyGranted);
+            entry.roles |= role.mask();
+        }
+        None => {
+            require!(
+                role_registry.members.len() < MAX_ROLE_MEMBERS,
+                RoleError::RoleRegistryFull
+            );
+            role_registry.members.push(RoleMember {
+                member,
+                roles: role.mask(),
+            });
+        }
+    }
+
+    emit!(RoleGranted {
+        role,
+        member,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!("Role {:?} granted to: {}", role, member);
+    Ok(())
+}
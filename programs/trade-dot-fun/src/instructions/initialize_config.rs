@@ -0,0 +1,65 @@
+use anchor_lang::prelude::*;
+use crate::{
+    program::TradeDotFun,
+    state::{Config, DepositLimits, OracleConfig, TokenMints},
+    errors::{ConfigError, TimelockError},
+    utils::{cluster::CLUSTER, validate_oracle_config, MAX_ACCEPTED_LSTS, MAX_LOCK_TIERS, MAX_TIMELOCK_DELAY},
+};
+
+#[derive(Accounts)]
+pub struct InitializeConfig<'info> {
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + 32 + 33 + 8 + 8 + 4 * 32 + (32 + 32 + 8 + 8 + 2 + 2) + 3 * 8 + 2 + 4 + MAX_ACCEPTED_LSTS * (32 + 32 + 8) + 4 + MAX_LOCK_TIERS * (8 + 2) + 1, // 8 (discriminator) + 32 (admin) + 33 (Option<Pubkey> pending_admin) + 8 (timelock_delay) + 8 (next_action_id) + 4 * 32 (mints) + 84 (oracle) + 3 * 8 (deposit_limits) + 2 (referral_bonus_bps) + 4 + MAX_ACCEPTED_LSTS * 72 (accepted_lsts) + 4 + MAX_LOCK_TIERS * 10 (lock_tiers) + 1 (bump)
+        seeds = [b"config"],
+        bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    // Only the upgrade authority of this program may create the config
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        constraint = program.programdata_address()? == Some(program_data.key()) @ ConfigError::InvalidUpgradeAuthority
+    )]
+    pub program: Program<'info, TradeDotFun>,
+
+    #[account(
+        constraint = program_data.upgrade_authority_address == Some(authority.key()) @ ConfigError::InvalidUpgradeAuthority
+    )]
+    pub program_data: Account<'info, ProgramData>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn initialize_config(
+    ctx: Context<InitializeConfig>,
+    admin: Pubkey,
+    timelock_delay: i64,
+    mints: TokenMints,
+    oracle: OracleConfig,
+) -> Result<()> {
+    require!(
+        (0..=MAX_TIMELOCK_DELAY).contains(&timelock_delay),
+        TimelockError::InvalidTimelockDelay
+    );
+    validate_oracle_config(&oracle)?;
+
+    let config = &mut ctx.accounts.config;
+    config.admin = admin;
+    config.pending_admin = None;
+    config.timelock_delay = timelock_delay;
+    config.next_action_id = 0;
+    config.mints = mints;
+    config.oracle = oracle;
+    config.deposit_limits = DepositLimits::default();
+    config.referral_bonus_bps = 0;
+    config.accepted_lsts = Vec::new();
+    config.lock_tiers = Vec::new();
+    config.bump = ctx.bumps.config;
+
+    msg!("Config initialized on {} with admin: {}, timelock delay: {}s", CLUSTER, admin, timelock_delay);
+    Ok(())
+}
@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+use crate::{
+    state::{Config, RoleRegistry},
+    utils::{check_authorized_admin, MAX_ROLE_MEMBERS},
+};
+
+#[derive(Accounts)]
+pub struct InitializeRoleRegistry<'info> {
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + 4 + MAX_ROLE_MEMBERS * (32 + 1) + 1, // 8 (discriminator) + 4 (Vec length) + MAX_ROLE_MEMBERS * (32 (Pubkey) + 1 (roles)) + 1 (bump)
+        seeds = [b"role_registry"],
+        bump,
+    )]
+    pub role_registry: Account<'info, RoleRegistry>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn initialize_role_registry(ctx: Context<InitializeRoleRegistry>) -> Result<()> {
+    // Check that the authority is the authorized admin
+    check_authorized_admin(&ctx.accounts.authority.key(), &ctx.accounts.config)?;
+
+    let role_registry = &mut ctx.accounts.role_registry;
+    role_registry.members = Vec::new();
+    role_registry.bump = ctx.bumps.role_registry;
+
+    msg!("Role registry initialized");
+    Ok(())
+}
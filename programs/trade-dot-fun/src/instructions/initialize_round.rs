@@ -1,6 +1,6 @@
 use anchor_lang::prelude::*;
 
-use crate::{Round, RoundState,  utils::check_authorized_admin};
+use crate::{debug_msg, Role, RoleRegistry, Round, RoundState, events::RoundInitialized, utils::check_role};
 
 
 #[derive(Accounts)]
@@ -17,23 +17,34 @@ pub struct InitializeRound<'info> {
     
     #[account(mut)]
     pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [b"role_registry"],
+        bump = role_registry.bump,
+    )]
+    pub role_registry: Account<'info, RoleRegistry>,
     
     pub system_program: Program<'info, System>,
 }
 
 pub fn initialize_round(ctx: Context<InitializeRound>, round_number: u64) -> Result<()> {
-    // Check that the authority is the authorized admin
-    check_authorized_admin(&ctx.accounts.authority.key())?;
+    // Check that the authority holds the round operator role
+    check_role(&ctx.accounts.authority.key(), &ctx.accounts.role_registry, Role::RoundOperator)?;
     
     let round = &mut ctx.accounts.round;
     
-    msg!("Step 1: Initializing round {}", round_number);
+    debug_msg!("Step 1: Initializing round {}", round_number);
     round.round_number = round_number;
     round.state = RoundState::Initialized;
     round.total_sol_deposited = 0;
     round.total_inf_received = 0;
     round.total_reward_tokens_minted = 0;
     round.total_chips = 0;
+
+    emit!(RoundInitialized {
+        round_number,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
     msg!("Round {} initialized with PDA: {}", round_number, round.key());
     Ok(())
 } 
\ No newline at end of file
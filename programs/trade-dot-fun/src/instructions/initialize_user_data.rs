@@ -1,5 +1,8 @@
 use anchor_lang::prelude::*;
-use crate::state::UserData;
+use crate::{state::UserData, utils::MAX_LOCK_TRANCHES};
+
+// 8 (discriminator) + 32 (Pubkey) + 8 (total_sol_deposited) + 8 (total_inf_equivalent) + 8 (deposit_count) + 8 (chip_remainder) + 33 (Option<Pubkey> referrer) + 4 + MAX_LOCK_TRANCHES * 16 (locks)
+pub const USER_DATA_SPACE: usize = 8 + 32 + 8 + 8 + 8 + 8 + 33 + 4 + MAX_LOCK_TRANCHES * (8 + 8);
 
 #[derive(Accounts)]
 pub struct InitializeUserData<'info> {
@@ -9,7 +12,7 @@ pub struct InitializeUserData<'info> {
     #[account(
         init,
         payer = user,
-        space = 8 + 32 + 8 + 8, // 8 (discriminator) + 32 (Pubkey) + 8 (total_sol_deposited) + 8 (total_inf_equivalent)
+        space = USER_DATA_SPACE,
         seeds = [b"user_data", user.key().as_ref()],
         bump,
     )]
@@ -18,11 +21,19 @@ pub struct InitializeUserData<'info> {
     pub system_program: Program<'info, System>,
 }
 
-pub fn initialize_user_data(ctx: Context<InitializeUserData>) -> Result<()> {
-    let user_data = &mut ctx.accounts.user_data;
-    user_data.user = ctx.accounts.user.key();
+// Sets up a fresh UserData, shared with the lazy creation on first deposit
+pub fn init_user_data(user_data: &mut UserData, user: Pubkey) {
+    user_data.user = user;
     user_data.total_sol_deposited = 0;
     user_data.total_inf_equivalent = 0;
+    user_data.deposit_count = 0;
+    user_data.chip_remainder = 0;
+    user_data.referrer = None;
+    user_data.locks = Vec::new();
+}
+
+pub fn initialize_user_data(ctx: Context<InitializeUserData>) -> Result<()> {
+    init_user_data(&mut ctx.accounts.user_data, ctx.accounts.user.key());
     
     msg!("User data initialized for: {}", ctx.accounts.user.key());
     Ok(())
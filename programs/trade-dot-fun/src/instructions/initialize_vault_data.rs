@@ -6,7 +6,7 @@ pub struct InitializeVaultData<'info> {
     #[account(
         init,
         payer = authority,
-        space = 8 + 8 + 33 + 8 + 8, // 8 (discriminator) + 8 (u64) + 33 (Option<Pubkey>) + 8 (exchange_rate) + 8 (last_price_update)
+        space = 8 + 8 + 8 + 8 + 8 + 1 + 1, // 8 (discriminator) + 8 (total_principal_sol) + 8 (current_round) + 8 (exchange_rate) + 8 (last_price_update) + 1 (is_round_active) + 1 (paused)
         seeds = [b"vault_data"],
         bump,
     )]
@@ -24,6 +24,7 @@ pub fn initialize_vault_data(ctx: Context<InitializeVaultData>) -> Result<()> {
     vault_data.current_round = 0;
     vault_data.exchange_rate = 0; // Initialize exchange rate to 0
     vault_data.last_price_update = Clock::get()?.unix_timestamp;
+    vault_data.paused = 0;
     msg!("Vault data initialized");
     Ok(())
 } 
\ No newline at end of file
@@ -0,0 +1,64 @@
+use anchor_lang::{
+    prelude::*,
+    system_program::{transfer, Transfer},
+    Discriminator,
+};
+use crate::{
+    state::UserData,
+    instructions::initialize_user_data::USER_DATA_SPACE,
+};
+
+#[derive(Accounts)]
+pub struct MigrateUserData<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    /// CHECK: May still have an older, shorter UserData layout, so it cannot be deserialized yet.
+    /// Address, owner and discriminator are verified below.
+    #[account(
+        mut,
+        seeds = [b"user_data", user.key().as_ref()],
+        bump,
+        owner = crate::ID,
+    )]
+    pub user_data: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn migrate_user_data(ctx: Context<MigrateUserData>) -> Result<()> {
+    let user_data = ctx.accounts.user_data.to_account_info();
+    require!(
+        user_data.try_borrow_data()?.starts_with(&UserData::DISCRIMINATOR),
+        ErrorCode::AccountDiscriminatorMismatch
+    );
+
+    let current_len = user_data.data_len();
+    if current_len >= USER_DATA_SPACE {
+        msg!("User data already up to date");
+        return Ok(());
+    }
+
+    // Top up rent for the larger account, paid by the user
+    let rent_needed = Rent::get()?
+        .minimum_balance(USER_DATA_SPACE)
+        .saturating_sub(user_data.lamports());
+    if rent_needed > 0 {
+        transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.user.to_account_info(),
+                    to: user_data.clone(),
+                },
+            ),
+            rent_needed,
+        )?;
+    }
+
+    // New fields are appended and start zeroed
+    user_data.realloc(USER_DATA_SPACE, true)?;
+
+    msg!("User data migrated from {} to {} bytes", current_len, USER_DATA_SPACE);
+    Ok(())
+}
@@ -7,7 +7,28 @@ pub mod participate_round;
 pub mod distribute_reward;
 pub mod distribute_zbtc_reward;
 pub mod initialize_vault_data;
-pub mod initialize_user_data; 
+pub mod initialize_user_data;
+pub mod initialize_config;
+pub mod queue_action;
+pub mod execute_action;
+pub mod cancel_action;
+pub mod accept_admin;
+pub mod initialize_role_registry;
+pub mod grant_role;
+pub mod revoke_role;
+pub mod set_pause;
+pub mod migrate_user_data;
+pub mod close_deposit_receipt;
+pub mod deposit_native_sol;
+pub mod deposit_inf;
+pub mod set_deposit_limits;
+pub mod bind_referrer;
+pub mod deposit_for;
+pub mod redeem_to_sol;
+pub mod request_withdrawal;
+pub mod fulfill_withdrawal;
+pub mod cancel_withdrawal;
+pub mod claim_referral_bonus;
 
 pub use deposit_sol::*;
 pub use redeem::*;
@@ -18,4 +39,25 @@ pub use participate_round::*;
 pub use distribute_reward::*;
 pub use distribute_zbtc_reward::*;
 pub use initialize_vault_data::*;
-pub use initialize_user_data::*;
\ No newline at end of file
+pub use initialize_user_data::*;
+pub use initialize_config::*;
+pub use queue_action::*;
+pub use execute_action::*;
+pub use cancel_action::*;
+pub use accept_admin::*;
+pub use initialize_role_registry::*;
+pub use grant_role::*;
+pub use revoke_role::*;
+pub use set_pause::*;
+pub use migrate_user_data::*;
+pub use close_deposit_receipt::*;
+pub use deposit_native_sol::*;
+pub use deposit_inf::*;
+pub use set_deposit_limits::*;
+pub use bind_referrer::*;
+pub use deposit_for::*;
+pub use redeem_to_sol::*;
+pub use request_withdrawal::*;
+pub use fulfill_withdrawal::*;
+pub use cancel_withdrawal::*;
+pub use claim_referral_bonus::*;
\ No newline at end of file
@@ -5,9 +5,10 @@ use anchor_spl::{
 };
 
 use crate::{
-    state::{Round, RoundState},
-    errors::RoundError,
-    utils::chip_token_mint,
+    state::{Config, Round, RoundState, VaultData},
+    errors::{RoundError, WithdrawalError},
+    events::Participated,
+    utils::{check_not_paused, PAUSE_PARTICIPATE},
 };
 
 #[derive(Accounts)]
@@ -16,6 +17,12 @@ pub struct ParticipateRound<'info> {
     #[account(mut)]
     pub user: Signer<'info>,
 
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, Config>,
+
     #[account(
         mut,
         seeds = [b"round", round_number.to_le_bytes().as_ref()],
@@ -23,9 +30,15 @@ pub struct ParticipateRound<'info> {
     )]
     pub round: Account<'info, Round>,
 
+    #[account(
+        seeds = [b"vault_data"],
+        bump,
+    )]
+    pub vault_data: Account<'info, VaultData>,
+
     #[account(
         mut,
-        address = chip_token_mint()
+        address = config.mints.chip
     )]
     pub reward_mint: InterfaceAccount<'info, Mint>,
 
@@ -46,12 +59,25 @@ pub struct ParticipateRound<'info> {
     )]
     pub round_reward_token_account: InterfaceAccount<'info, TokenAccount>,
 
+    /// CHECK: The user's withdrawal ticket, which must not exist. This only stops a
+    /// wallet that is leaving from joining more rounds: CHIP is a transferable token
+    /// minted at deposit, not a claim on principal, so it cannot be tied to the
+    /// ticketed amount. Chips entered before the request, or moved to another wallet,
+    /// still take part.
+    #[account(
+        seeds = [b"withdrawal_ticket", user.key().as_ref()],
+        bump,
+    )]
+    pub withdrawal_ticket: UncheckedAccount<'info>,
+
     pub reward_mint_program: Interface<'info, TokenInterface>,
     pub system_program: Program<'info, System>,
     pub associated_token_program: Program<'info, AssociatedToken>,
 }
 
 pub fn participate_round(ctx: Context<ParticipateRound>, round_number: u64) -> Result<()> {
+    check_not_paused(&ctx.accounts.vault_data, PAUSE_PARTICIPATE)?;
+
     // Verify round is active
     require!(
         ctx.accounts.round.state == RoundState::Started,
@@ -64,6 +90,11 @@ pub fn participate_round(ctx: Context<ParticipateRound>, round_number: u64) -> R
         RoundError::InvalidRoundNumber
     );
 
+    require!(
+        ctx.accounts.withdrawal_ticket.data_is_empty(),
+        WithdrawalError::WithdrawalPending
+    );
+
     // Transfer 1 reward token from user to round
     let cpi_accounts = Transfer {
         from: ctx.accounts.user_reward_token_account.to_account_info(),
@@ -80,6 +111,14 @@ pub fn participate_round(ctx: Context<ParticipateRound>, round_number: u64) -> R
     ctx.accounts.round.total_chips = ctx.accounts.round.total_chips
         .checked_add(1)
         .unwrap();
+
+    emit!(Participated {
+        user: ctx.accounts.user.key(),
+        round_number,
+        chips: 1,
+        total_chips: ctx.accounts.round.total_chips,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
     
 
     Ok(())
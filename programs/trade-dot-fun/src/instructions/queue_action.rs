@@ -0,0 +1,94 @@
+use anchor_lang::prelude::*;
+use crate::{
+    state::{AdminAction, Config, PendingAction},
+    errors::{ConfigError, ReferralError, TimelockError},
+    events::ActionQueued,
+    utils::{check_authorized_admin, validate_accepted_lst, validate_lock_tiers, validate_oracle_config, BPS_DENOMINATOR, MAX_TIMELOCK_DELAY},
+};
+
+#[derive(Accounts)]
+pub struct QueueAction<'info> {
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + 8 + AdminAction::MAX_SIZE + 32 + 8 + 8 + 1, // 8 (discriminator) + 8 (action_id) + AdminAction::MAX_SIZE (action) + 32 (proposer) + 8 (queued_at) + 8 (eta) + 1 (bump)
+        seeds = [b"pending_action", config.next_action_id.to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub pending_action: Account<'info, PendingAction>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn queue_action(ctx: Context<QueueAction>, action: AdminAction) -> Result<()> {
+    // Check that the authority is the authorized admin
+    check_authorized_admin(&ctx.accounts.authority.key(), &ctx.accounts.config)?;
+
+    // Reject parameters that could never be executed
+    match &action {
+        AdminAction::SetTimelockDelay { delay } => {
+            require!(
+                (0..=MAX_TIMELOCK_DELAY).contains(delay),
+                TimelockError::InvalidTimelockDelay
+            );
+        }
+        AdminAction::SetOracleConfig { oracle } => validate_oracle_config(oracle)?,
+        AdminAction::AddAcceptedLst { lst } => {
+            validate_accepted_lst(lst, &ctx.accounts.config)?;
+            require!(
+                ctx.accounts.config.find_accepted_lst(&lst.mint).is_none(),
+                ConfigError::LstAlreadyAccepted
+            );
+        }
+        AdminAction::SetReferralBonus { bps } => {
+            require!(
+                (*bps as u64) <= BPS_DENOMINATOR,
+                ReferralError::InvalidReferralBonus
+            );
+        }
+        AdminAction::SetLockTiers { tiers } => validate_lock_tiers(tiers)?,
+        AdminAction::RemoveAcceptedLst { mint } => {
+            require!(
+                ctx.accounts.config.find_accepted_lst(mint).is_some(),
+                ConfigError::LstNotAccepted
+            );
+        }
+        _ => {}
+    }
+
+    let config = &mut ctx.accounts.config;
+    let now = Clock::get()?.unix_timestamp;
+    let eta = now.checked_add(config.timelock_delay).unwrap();
+    let action_id = config.next_action_id;
+
+    let pending_action = &mut ctx.accounts.pending_action;
+    pending_action.action_id = action_id;
+    pending_action.action = action.clone();
+    pending_action.proposer = ctx.accounts.authority.key();
+    pending_action.queued_at = now;
+    pending_action.eta = eta;
+    pending_action.bump = ctx.bumps.pending_action;
+
+    config.next_action_id = action_id.checked_add(1).unwrap();
+
+    emit!(ActionQueued {
+        action_id,
+        action,
+        proposer: ctx.accounts.authority.key(),
+        eta,
+        timestamp: now,
+    });
+
+    msg!("Action {} queued, executable after {}", action_id, eta);
+    Ok(())
+}
@@ -1,11 +1,14 @@
 use anchor_lang::prelude::*;
 use anchor_spl::token_interface::{TokenAccount, Mint, TokenInterface, Transfer};
-use pyth_solana_receiver_sdk::price_update::{get_feed_id_from_hex, PriceUpdateV2};
+use pyth_solana_receiver_sdk::price_update::PriceUpdateV2;
 
 use crate::{
-    state::{UserData, VaultData},
+    debug_msg,
+    state::{Config, UserData, VaultData},
     errors::RedeemError,
-    utils::{find_vault_authority_pda, INF_USD_PRICE_FEED_ID, SOL_USD_PRICE_FEED_ID, MAXIMUM_AGE},
+    events::Redeemed,
+    utils::{find_vault_authority_pda, check_not_paused, PAUSE_REDEEM},
+    utils::redeem::{apply_redeem, RedeemAmounts},
 };
 
 #[derive(Accounts)]
@@ -13,7 +16,13 @@ pub struct Redeem<'info> {
     // The user requesting redemption
     #[account(mut)]
     pub user: Signer<'info>,
-    
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, Config>,
+
     // User data account to check principal amount
     #[account(
         mut,
@@ -30,7 +39,10 @@ pub struct Redeem<'info> {
     )]
     pub vault_data: Account<'info, VaultData>,
     
-    // The token mint of the tokens being redeemed
+    // The token mint of the tokens being redeemed (INF)
+    #[account(
+        address = config.mints.inf
+    )]
     pub token_mint: InterfaceAccount<'info, Mint>,
     pub token_program: Interface<'info, TokenInterface>,
     
@@ -68,114 +80,37 @@ pub struct Redeem<'info> {
     pub price_update_sol: Account<'info, PriceUpdateV2>,
 }
 
-pub fn redeem(ctx: Context<Redeem>) -> Result<()> {
+pub fn redeem(ctx: Context<Redeem>, sol_amount: u64) -> Result<()> {
+    check_not_paused(&ctx.accounts.vault_data, PAUSE_REDEEM)?;
+
     let is_round_active = ctx.accounts.vault_data.is_round_active;
     require!(!is_round_active, RedeemError::RoundActive);
 
     // Step 1: Verify the vault_authority is the correct PDA
-    msg!("Step 1: Verifying vault authority");
+    debug_msg!("Step 1: Verifying vault authority");
     let (vault_authority, bump) = find_vault_authority_pda();
     require_keys_eq!(ctx.accounts.vault_authority.key(), vault_authority);
     
-    // Step 2: Check if user has any principal to redeem
-    msg!("Step 2: Checking user principal");
-    let user_data = &mut ctx.accounts.user_data;
-    require!(
-        user_data.total_sol_deposited > 0,
-        RedeemError::NoPrincipal
-    );
-    
-    // Step 3: Calculate user's initial exchange rate from stored values
-    msg!("Step 3: Calculating initial exchange rate");
-    let scale_factor = 1_000_000; // 10^6 scaling for precision
-    let initial_exchange_rate = user_data.total_inf_equivalent
-        .checked_mul(scale_factor)
-        .and_then(|x| x.checked_div(user_data.total_sol_deposited))
-        .unwrap_or(0);
-    
-    msg!("Initial exchange rate (scaled by 10^6): {}", initial_exchange_rate);
-    
-    // Step 4: Get current INF/USD price from Pyth
-    msg!("Step 4: Getting INF/USD price");
-    let inf_price_update = &ctx.accounts.price_update_inf;
-    let inf_price_data = inf_price_update.get_price_no_older_than(
-        &Clock::get()?,
-        MAXIMUM_AGE,
-        &get_feed_id_from_hex(INF_USD_PRICE_FEED_ID)?,
+    // Step 2: Check the amount, price it and update user and vault principal
+    debug_msg!("Step 2: Applying redemption of {}", sol_amount);
+    let RedeemAmounts {
+        sol_principal,
+        base_inf_amount,
+        inf_to_redeem,
+        initial_exchange_rate,
+        current_exchange_rate,
+    } = apply_redeem(
+        &ctx.accounts.config.oracle,
+        &mut ctx.accounts.user_data,
+        &mut ctx.accounts.vault_data,
+        &ctx.accounts.price_update_inf,
+        &ctx.accounts.price_update_sol,
+        sol_amount,
+        ctx.accounts.vault_token_account.amount,
     )?;
 
-    // Step 5: Get current SOL/USD price from Pyth
-    msg!("Step 5: Getting SOL/USD price");
-    let sol_price_update = &ctx.accounts.price_update_sol;
-    let sol_price_data = sol_price_update.get_price_no_older_than(
-        &Clock::get()?,
-        MAXIMUM_AGE,
-        &get_feed_id_from_hex(SOL_USD_PRICE_FEED_ID)?,
-    )?;
-
-    msg!("INF/USD price data: {:?}", inf_price_data);
-    msg!("SOL/USD price data: {:?}", sol_price_data);
-    
-    // Step 6: Calculate current INF/SOL price ratio
-    msg!("Step 6: Calculating current INF/SOL price ratio");
-    let inf_price_raw = inf_price_data.price as u64;
-    let sol_price_raw = sol_price_data.price as u64;
-    
-    let current_exchange_rate = inf_price_raw
-        .checked_mul(scale_factor)
-        .and_then(|x| x.checked_div(sol_price_raw))
-        .ok_or(RedeemError::InvalidPriceData)?;
-
-    msg!("Current exchange rate (scaled by 10^6): {}", current_exchange_rate);
-    
-    // Step 7: Ensure current exchange rate isn't lower than initial (solvency check)
-    require!(
-        current_exchange_rate >= initial_exchange_rate,
-        RedeemError::ExchangeRateDecrease
-    );
-    
-    // Step 8: Calculate INF amount to redeem with price appreciation
-    msg!("Step 8: Calculating INF to redeem with appreciation");
-    let base_inf_amount = user_data.total_inf_equivalent;
-    let sol_principal = user_data.total_sol_deposited;
-
-    // Calculate the price ratio between current and initial rates
-    let price_ratio = current_exchange_rate
-        .checked_mul(scale_factor)
-        .and_then(|x| x.checked_div(initial_exchange_rate))
-        .ok_or(RedeemError::InvalidPriceData)?;
-
-    // Calculate INF amount with appreciation
-    let inf_to_redeem = base_inf_amount
-        .checked_mul(price_ratio)
-        .and_then(|x| x.checked_div(scale_factor))
-        .ok_or(RedeemError::InvalidPriceData)?;
-
-    msg!("Base INF amount: {}", base_inf_amount);
-    msg!("Price ratio (scaled by 10^6): {}", price_ratio);
-    msg!("INF to redeem with appreciation: {}", inf_to_redeem);
-    
-    // Step 9: Verify the vault has enough tokens
-    msg!("Step 9: Verifying vault balance");
-    require!(
-        ctx.accounts.vault_token_account.amount >= inf_to_redeem,
-        RedeemError::InsufficientVaultBalance
-    );
-    
-    // Step 10: Update user data to reflect the redemption (zero out values)
-    msg!("Step 10: Updating user principal");
-    user_data.total_sol_deposited = 0;
-    user_data.total_inf_equivalent = 0;
-    
-    // Step 11: Update vault data
-    msg!("Step 11: Updating vault data");
-    let vault_data = &mut ctx.accounts.vault_data;
-    vault_data.total_principal_sol = vault_data.total_principal_sol
-        .checked_sub(sol_principal)
-        .unwrap();
-    
-    // Step 12: Transfer INF tokens from vault to user
-    msg!("Step 12: Transferring {} INF tokens to user", inf_to_redeem);
+    // Step 3: Transfer INF tokens from vault to user
+    debug_msg!("Step 3: Transferring {} INF tokens to user", inf_to_redeem);
     let vault_authority_seeds = &[b"vault_authority".as_ref(), &[bump]];
     let signer_seeds = &[&vault_authority_seeds[..]];
     
@@ -190,25 +125,45 @@ pub fn redeem(ctx: Context<Redeem>) -> Result<()> {
     
     anchor_spl::token_interface::transfer(cpi_ctx, inf_to_redeem)?;
     
-    // Calculate price change percentage
-    let price_change_percent = if initial_exchange_rate > 0 {
-        current_exchange_rate
-            .checked_sub(initial_exchange_rate)
+    // Calculate INF appreciation against SOL since deposit (a lower INF per SOL rate)
+    let price_change_percent = if current_exchange_rate > 0 {
+        initial_exchange_rate
+            .checked_sub(current_exchange_rate)
             .and_then(|change| change.checked_mul(100))
-            .and_then(|scaled_change| scaled_change.checked_div(initial_exchange_rate))
+            .and_then(|scaled_change| scaled_change.checked_div(current_exchange_rate))
             .unwrap_or(0)
     } else {
         0
     };
     
     // Log successful redemption
-    msg!("Successfully redeemed principal with appreciation");
-    msg!("SOL principal redeemed: {}", sol_principal);
-    msg!("Base INF amount: {}", base_inf_amount);
-    msg!("INF tokens received (with appreciation): {}", inf_to_redeem);
-    msg!("Initial exchange rate: {}", initial_exchange_rate);
-    msg!("Current exchange rate: {}", current_exchange_rate);
-    msg!("Price change: {}%", price_change_percent);
+    debug_msg!("Successfully redeemed principal");
+    debug_msg!("SOL principal redeemed: {}", sol_principal);
+    debug_msg!("Base INF amount: {}", base_inf_amount);
+    debug_msg!("INF tokens received: {}", inf_to_redeem);
+    debug_msg!("Initial exchange rate: {}", initial_exchange_rate);
+    debug_msg!("Current exchange rate: {}", current_exchange_rate);
+    debug_msg!("INF appreciation: {}%", price_change_percent);
+
+    msg!(
+        "Redeem: user={} sol={} inf={} rate={}",
+        ctx.accounts.user.key(),
+        sol_principal,
+        inf_to_redeem,
+        current_exchange_rate,
+    );
+
+    emit!(Redeemed {
+        user: ctx.accounts.user.key(),
+        sol_principal,
+        base_inf_amount,
+        inf_redeemed: inf_to_redeem,
+        initial_exchange_rate,
+        current_exchange_rate,
+        remaining_principal_sol: ctx.accounts.user_data.total_sol_deposited,
+        total_principal_sol: ctx.accounts.vault_data.total_principal_sol,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
     
     Ok(())
 } 
\ No newline at end of file
@@ -0,0 +1,287 @@
+use anchor_lang::{
+    prelude::*,
+    solana_program::program_error::ProgramError,
+};
+use anchor_spl::token_interface::{
+    close_account, transfer_checked, CloseAccount, Mint, TokenAccount, TokenInterface,
+    TransferChecked,
+};
+use pyth_solana_receiver_sdk::price_update::PriceUpdateV2;
+
+use crate::{
+    debug_msg,
+    state::{Config, UserData, VaultData},
+    errors::{RedeemError, SwapError},
+    events::{Redeemed, RedeemedToSol},
+    utils::{find_vault_authority_pda, jupiter_program_id, check_not_paused, PAUSE_REDEEM},
+    utils::jupiter::{invoke_jupiter_swap, ExpectedRoute},
+    utils::redeem::{apply_redeem, RedeemAmounts},
+};
+
+// Same as Redeem, but the INF is moved to a temporary account and swapped to SOL there. The swap is
+// signed by a per-user redeem authority, never by the vault authority, so a route cannot reach the
+// vault's other token accounts
+#[derive(Accounts)]
+pub struct RedeemToSol<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        seeds = [b"user_data", user.key().as_ref()],
+        bump,
+    )]
+    pub user_data: Account<'info, UserData>,
+
+    #[account(
+        mut,
+        seeds = [b"vault_data"],
+        bump,
+    )]
+    pub vault_data: Account<'info, VaultData>,
+
+    #[account(
+        address = config.mints.inf
+    )]
+    pub inf_mint: InterfaceAccount<'info, Mint>,
+    pub inf_mint_program: Interface<'info, TokenInterface>,
+
+    #[account(
+        address = config.mints.wsol
+    )]
+    pub wsol_mint: InterfaceAccount<'info, Mint>,
+    pub wsol_mint_program: Interface<'info, TokenInterface>,
+
+    #[account(
+        mut,
+        associated_token::mint = inf_mint,
+        associated_token::authority = vault_authority,
+        associated_token::token_program = inf_mint_program,
+    )]
+    pub vault_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    // Temporary INF account holding only the redeemed INF, the route source
+    #[account(
+        init,
+        payer = user,
+        seeds = [b"redeem_inf", user.key().as_ref()],
+        bump,
+        token::mint = inf_mint,
+        token::authority = redeem_authority,
+        token::token_program = inf_mint_program,
+    )]
+    pub temp_inf_account: InterfaceAccount<'info, TokenAccount>,
+
+    // Receives any redeemed INF the route did not swap
+    #[account(
+        mut,
+        associated_token::mint = inf_mint,
+        associated_token::authority = user,
+        associated_token::token_program = inf_mint_program,
+    )]
+    pub user_inf_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    // Temporary WSOL account used as the route destination, closed to the user in this instruction
+    #[account(
+        init,
+        payer = user,
+        seeds = [b"redeem_sol", user.key().as_ref()],
+        bump,
+        token::mint = wsol_mint,
+        token::authority = redeem_authority,
+        token::token_program = wsol_mint_program,
+    )]
+    pub temp_wsol_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// CHECK: PDA owning the user's temporary accounts, signs the swap
+    #[account(
+        seeds = [b"redeem_authority", user.key().as_ref()],
+        bump,
+    )]
+    pub redeem_authority: AccountInfo<'info>,
+
+    /// CHECK: This is the program's vault authority PDA
+    #[account(
+        seeds = [b"vault_authority"],
+        bump,
+    )]
+    pub vault_authority: AccountInfo<'info>,
+
+    /// CHECK: This is Jupiter program with address verified
+    #[account(
+        address = jupiter_program_id() @ ProgramError::IncorrectProgramId
+    )]
+    pub jupiter_program: AccountInfo<'info>,
+
+    pub system_program: Program<'info, System>,
+
+    // Pyth price feeds for getting current exchange rate
+    /// CHECK: Pyth price update account for INF/USD
+    pub price_update_inf: Account<'info, PriceUpdateV2>,
+
+    /// CHECK: Pyth price update account for SOL/USD
+    pub price_update_sol: Account<'info, PriceUpdateV2>,
+}
+
+pub fn redeem_to_sol(
+    ctx: Context<RedeemToSol>,
+    sol_amount: u64,
+    data: Vec<u8>,
+    min_sol_out: u64,
+) -> Result<()> {
+    check_not_paused(&ctx.accounts.vault_data, PAUSE_REDEEM)?;
+
+    let is_round_active = ctx.accounts.vault_data.is_round_active;
+    require!(!is_round_active, RedeemError::RoundActive);
+
+    // Step 1: Verify the vault_authority is the correct PDA
+    debug_msg!("Step 1: Verifying vault authority");
+    let (vault_authority, bump) = find_vault_authority_pda();
+    require_keys_eq!(ctx.accounts.vault_authority.key(), vault_authority);
+
+    // Step 2: Check the amount, price it and update user and vault principal
+    debug_msg!("Step 2: Applying redemption of {}", sol_amount);
+    let RedeemAmounts {
+        sol_principal,
+        base_inf_amount,
+        inf_to_redeem,
+        initial_exchange_rate,
+        current_exchange_rate,
+    } = apply_redeem(
+        &ctx.accounts.config.oracle,
+        &mut ctx.accounts.user_data,
+        &mut ctx.accounts.vault_data,
+        &ctx.accounts.price_update_inf,
+        &ctx.accounts.price_update_sol,
+        sol_amount,
+        ctx.accounts.vault_token_account.amount,
+    )?;
+
+    // Step 3: Move the redeemed INF out of the vault into the temporary account
+    debug_msg!("Step 3: Transferring {} INF to temporary account", inf_to_redeem);
+    let vault_authority_seeds = &[b"vault_authority".as_ref(), &[bump]];
+    transfer_checked(
+        CpiContext::new_with_signer(
+            ctx.accounts.inf_mint_program.to_account_info(),
+            TransferChecked {
+                from: ctx.accounts.vault_token_account.to_account_info(),
+                mint: ctx.accounts.inf_mint.to_account_info(),
+                to: ctx.accounts.temp_inf_account.to_account_info(),
+                authority: ctx.accounts.vault_authority.to_account_info(),
+            },
+            &[&vault_authority_seeds[..]],
+        ),
+        inf_to_redeem,
+        ctx.accounts.inf_mint.decimals,
+    )?;
+
+    // Step 4: Swap the redeemed INF to WSOL, signed by the user's redeem authority
+    debug_msg!("Step 4: Executing Jupiter swap of up to {} INF", inf_to_redeem);
+    let user_key = ctx.accounts.user.key();
+    let redeem_authority_seeds = &[
+        b"redeem_authority".as_ref(),
+        user_key.as_ref(),
+        &[ctx.bumps.redeem_authority],
+    ];
+    let signer_seeds = &[&redeem_authority_seeds[..]];
+
+    invoke_jupiter_swap(
+        &ctx.accounts.jupiter_program.key(),
+        ctx.remaining_accounts,
+        data,
+        &ExpectedRoute {
+            authority: ctx.accounts.redeem_authority.key(),
+            source: ctx.accounts.temp_inf_account.key(),
+            destination: ctx.accounts.temp_wsol_account.key(),
+        },
+        signer_seeds,
+    )?;
+
+    // Step 5: Check the swap returned enough SOL
+    debug_msg!("Step 5: Getting updated account balances");
+    ctx.accounts.temp_inf_account.reload()?;
+    ctx.accounts.temp_wsol_account.reload()?;
+    let inf_remaining = ctx.accounts.temp_inf_account.amount;
+    let inf_swapped = inf_to_redeem
+        .checked_sub(inf_remaining)
+        .ok_or(SwapError::InvalidSwapDirection)?;
+    let sol_received = ctx.accounts.temp_wsol_account.amount;
+    require!(inf_swapped > 0, SwapError::InvalidSwapDirection);
+    require!(sol_received >= min_sol_out, RedeemError::SolOutputTooLow);
+    debug_msg!("INF swapped: {} SOL received: {}", inf_swapped, sol_received);
+
+    // Step 6: Pay out any INF the route left unswapped
+    if inf_remaining > 0 {
+        debug_msg!("Step 6: Transferring {} unswapped INF to user", inf_remaining);
+        transfer_checked(
+            CpiContext::new_with_signer(
+                ctx.accounts.inf_mint_program.to_account_info(),
+                TransferChecked {
+                    from: ctx.accounts.temp_inf_account.to_account_info(),
+                    mint: ctx.accounts.inf_mint.to_account_info(),
+                    to: ctx.accounts.user_inf_token_account.to_account_info(),
+                    authority: ctx.accounts.redeem_authority.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            inf_remaining,
+            ctx.accounts.inf_mint.decimals,
+        )?;
+    }
+
+    // Step 7: Close the temporary accounts, unwrapping the SOL. The SOL and the rent go to the user
+    debug_msg!("Step 7: Closing temporary accounts");
+    close_account(CpiContext::new_with_signer(
+        ctx.accounts.inf_mint_program.to_account_info(),
+        CloseAccount {
+            account: ctx.accounts.temp_inf_account.to_account_info(),
+            destination: ctx.accounts.user.to_account_info(),
+            authority: ctx.accounts.redeem_authority.to_account_info(),
+        },
+        signer_seeds,
+    ))?;
+    close_account(CpiContext::new_with_signer(
+        ctx.accounts.wsol_mint_program.to_account_info(),
+        CloseAccount {
+            account: ctx.accounts.temp_wsol_account.to_account_info(),
+            destination: ctx.accounts.user.to_account_info(),
+            authority: ctx.accounts.redeem_authority.to_account_info(),
+        },
+        signer_seeds,
+    ))?;
+
+    msg!(
+        "Redeem to SOL: user={} sol={} inf={} sol_out={}",
+        ctx.accounts.user.key(),
+        sol_principal,
+        inf_swapped,
+        sol_received,
+    );
+
+    let timestamp = Clock::get()?.unix_timestamp;
+    emit!(Redeemed {
+        user: ctx.accounts.user.key(),
+        sol_principal,
+        base_inf_amount,
+        inf_redeemed: inf_to_redeem,
+        initial_exchange_rate,
+        current_exchange_rate,
+        remaining_principal_sol: ctx.accounts.user_data.total_sol_deposited,
+        total_principal_sol: ctx.accounts.vault_data.total_principal_sol,
+        timestamp,
+    });
+    emit!(RedeemedToSol {
+        user: ctx.accounts.user.key(),
+        inf_swapped,
+        sol_received,
+        timestamp,
+    });
+
+    Ok(())
+}
\ No newline at end of file
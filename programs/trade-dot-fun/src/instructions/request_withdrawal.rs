@@ -0,0 +1,104 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use crate::{
+    state::{Config, UserData, VaultData, WithdrawalTicket},
+    errors::{LockError, RedeemError, WithdrawalError},
+    events::WithdrawalRequested,
+    utils::{check_not_paused, PAUSE_REDEEM},
+};
+
+// Space: 8 (discriminator) + 32 (user) + 8 (sol_amount) + 8 (round_number) + 8 (requested_at) + 1 (bump)
+pub const WITHDRAWAL_TICKET_SPACE: usize = 8 + 32 + 8 + 8 + 8 + 1;
+
+#[derive(Accounts)]
+pub struct RequestWithdrawal<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        seeds = [b"user_data", user.key().as_ref()],
+        bump,
+    )]
+    pub user_data: Account<'info, UserData>,
+
+    #[account(
+        seeds = [b"vault_data"],
+        bump,
+    )]
+    pub vault_data: Account<'info, VaultData>,
+
+    // One pending withdrawal per user, closed when fulfilled or cancelled
+    #[account(
+        init,
+        payer = user,
+        space = WITHDRAWAL_TICKET_SPACE,
+        seeds = [b"withdrawal_ticket", user.key().as_ref()],
+        bump,
+    )]
+    pub withdrawal_ticket: Account<'info, WithdrawalTicket>,
+
+    #[account(
+        address = config.mints.inf
+    )]
+    pub token_mint: InterfaceAccount<'info, Mint>,
+    pub token_program: Interface<'info, TokenInterface>,
+
+    // Must exist so the ticket can be fulfilled without the user
+    #[account(
+        associated_token::mint = token_mint,
+        associated_token::authority = user,
+        associated_token::token_program = token_program,
+    )]
+    pub user_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn request_withdrawal(ctx: Context<RequestWithdrawal>, sol_amount: u64) -> Result<()> {
+    check_not_paused(&ctx.accounts.vault_data, PAUSE_REDEEM)?;
+    require!(
+        ctx.accounts.vault_data.is_round_active,
+        WithdrawalError::RoundNotActive
+    );
+
+    // Same amount checks as redeem, repeated when the ticket is fulfilled
+    let user_data = &ctx.accounts.user_data;
+    require!(
+        sol_amount > 0 && sol_amount <= user_data.total_sol_deposited,
+        RedeemError::InvalidRedeemAmount
+    );
+    let now = Clock::get()?.unix_timestamp;
+    let redeemable_sol = user_data.total_sol_deposited
+        .checked_sub(user_data.locked_principal(now))
+        .unwrap();
+    require!(sol_amount <= redeemable_sol, LockError::PrincipalLocked);
+
+    let round_number = ctx.accounts.vault_data.current_round;
+    let ticket = &mut ctx.accounts.withdrawal_ticket;
+    ticket.user = ctx.accounts.user.key();
+    ticket.sol_amount = sol_amount;
+    ticket.round_number = round_number;
+    ticket.requested_at = now;
+    ticket.bump = ctx.bumps.withdrawal_ticket;
+
+    emit!(WithdrawalRequested {
+        user: ticket.user,
+        sol_amount,
+        round_number,
+        timestamp: now,
+    });
+
+    msg!(
+        "Withdrawal requested: user={} sol={} round={}",
+        ticket.user,
+        sol_amount,
+        round_number
+    );
+    Ok(())
+}
\ No newline at end of file
@@ -0,0 +1,50 @@
+use anchor_lang::prelude::*;
+use crate::{
+    state::{Config, Role, RoleRegistry},
+    errors::RoleError,
+    events::RoleRevoked,
+    utils::check_authorized_admin,
+};
+
+#[derive(Accounts)]
+pub struct RevokeRole<'info> {
+    #[account(
+        mut,
+        seeds = [b"role_registry"],
+        bump = role_registry.bump,
+    )]
+    pub role_registry: Account<'info, RoleRegistry>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    pub authority: Signer<'info>,
+}
+
+pub fn revoke_role(ctx: Context<RevokeRole>, role: Role, member: Pubkey) -> Result<()> {
+    // Check that the authority is the authorized admin
+    check_authorized_admin(&ctx.accounts.authority.key(), &ctx.accounts.config)?;
+
+    let role_registry = &mut ctx.accounts.role_registry;
+    let entry = role_registry
+        .members
+        .iter_mut()
+        .find(|m| m.member == member && m.roles & role.mask() != 0)
+        .ok_or(RoleError::RoleNotGranted)?;
+    entry.roles &= !role.mask();
+
+    // Free the slot once the member holds no roles
+    role_registry.members.retain(|m| m.roles != 0);
+
+    emit!(RoleRevoked {
+        role,
+        member,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!("Role {:?} revoked from: {}", role, member);
+    Ok(())
+}
@@ -0,0 +1,52 @@
+use anchor_lang::prelude::*;
+use crate::{
+    state::{Config, DepositLimits},
+    errors::DepositError,
+    events::DepositLimitsUpdated,
+    utils::check_authorized_admin,
+};
+
+#[derive(Accounts)]
+pub struct SetDepositLimits<'info> {
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    pub authority: Signer<'info>,
+}
+
+pub fn set_deposit_limits(ctx: Context<SetDepositLimits>, limits: DepositLimits) -> Result<()> {
+    // Check that the authority is the authorized admin
+    check_authorized_admin(&ctx.accounts.authority.key(), &ctx.accounts.config)?;
+
+    // A zero cap means no cap, a non-zero one must leave room for the minimum deposit
+    for cap in [limits.max_user_principal, limits.max_total_principal] {
+        require!(
+            cap == 0 || limits.min_deposit <= cap,
+            DepositError::InvalidDepositLimits
+        );
+    }
+
+    // Not timelocked: caps are tightened during staged launches and incidents
+    let config = &mut ctx.accounts.config;
+    let previous = config.deposit_limits;
+    config.deposit_limits = limits;
+
+    emit!(DepositLimitsUpdated {
+        previous,
+        limits,
+        authority: ctx.accounts.authority.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!(
+        "Deposit limits set: min {} per user {} total {}",
+        limits.min_deposit,
+        limits.max_user_principal,
+        limits.max_total_principal
+    );
+    Ok(())
+}
\ No newline at end of file
@@ -0,0 +1,62 @@
+use anchor_lang::prelude::*;
+use crate::{
+    state::{Config, Role, RoleRegistry, VaultData},
+    errors::PausedError,
+    events::PauseUpdated,
+    utils::{check_role, PAUSE_ALL},
+};
+
+#[derive(Accounts)]
+pub struct SetPause<'info> {
+    #[account(
+        mut,
+        seeds = [b"vault_data"],
+        bump,
+    )]
+    pub vault_data: Account<'info, VaultData>,
+
+    #[account(
+        seeds = [b"role_registry"],
+        bump = role_registry.bump,
+    )]
+    pub role_registry: Account<'info, RoleRegistry>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    pub authority: Signer<'info>,
+}
+
+pub fn set_pause(ctx: Context<SetPause>, paused: u8) -> Result<()> {
+    require!(paused & !PAUSE_ALL == 0, PausedError::InvalidPauseMask);
+
+    // Lifting any flag needs the admin, who doesn't need the guardian role for it.
+    // Only adding flags is left to guardians
+    let vault_data = &mut ctx.accounts.vault_data;
+    let previous = vault_data.paused;
+    let lifted = previous & !paused;
+    if lifted != 0 {
+        require_keys_eq!(
+            ctx.accounts.authority.key(),
+            ctx.accounts.config.admin,
+            PausedError::UnpauseRequiresAdmin
+        );
+    } else {
+        check_role(&ctx.accounts.authority.key(), &ctx.accounts.role_registry, Role::Guardian)?;
+    }
+
+    vault_data.paused = paused;
+
+    emit!(PauseUpdated {
+        previous,
+        paused,
+        authority: ctx.accounts.authority.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!("Pause mask set to: {:#07b}", paused);
+    Ok(())
+}
@@ -1,8 +1,9 @@
 use anchor_lang::prelude::*;
 use crate::{
-    state::{Round, RoundState, VaultData},
+    state::{Role, RoleRegistry, Round, RoundState, VaultData},
     errors::RoundError,
-    utils::check_authorized_admin,
+    events::RoundStarted,
+    utils::check_role,
 };
 
 #[derive(Accounts)]
@@ -24,13 +25,19 @@ pub struct StartRound<'info> {
     
     #[account(mut)]
     pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [b"role_registry"],
+        bump = role_registry.bump,
+    )]
+    pub role_registry: Account<'info, RoleRegistry>,
     
     pub system_program: Program<'info, System>,
 }
 
 pub fn start_round(ctx: Context<StartRound>, round_number: u64) -> Result<()> {
-    // Check that the authority is the authorized admin
-    check_authorized_admin(&ctx.accounts.authority.key())?;
+    // Check that the authority holds the round operator role
+    check_role(&ctx.accounts.authority.key(), &ctx.accounts.role_registry, Role::RoundOperator)?;
     
     let round = &mut ctx.accounts.round;
     let vault_data = &mut ctx.accounts.vault_data;
@@ -40,8 +47,15 @@ pub fn start_round(ctx: Context<StartRound>, round_number: u64) -> Result<()> {
     );
     round.state = RoundState::Started;
     vault_data.current_round = round_number;
+    // Blocks redemptions until the round closes, withdrawals are queued instead
+    vault_data.is_round_active = true;
     round.start_time = Clock::get()?.unix_timestamp;
 
+    emit!(RoundStarted {
+        round_number,
+        start_time: round.start_time,
+    });
+
     msg!("Round started: {}", round_number);
     Ok(())
 } 
\ No newline at end of file
@@ -3,10 +3,17 @@ pub mod state;
 pub mod errors;
 pub mod utils;
 pub mod instructions;
+pub mod events;
 
 pub use instructions::*;
 pub use state::*;
 
+// Program ID per cluster, see utils::cluster for the matching address sets
+#[cfg(feature = "localnet")]
+declare_id!("D13DXSpyGwvMwvBS77bXCKjvTx8KEz7LGVPeTQFgcANn");
+#[cfg(feature = "devnet")]
+declare_id!("2ZfYq8t5JTfmUjMYWZTjxTdptd5VF9xJCvehAe8V54M1");
+#[cfg(not(any(feature = "localnet", feature = "devnet")))]
 declare_id!("B1ph2kyNtkhscRQ3R1CAwMNM4PbGGvphHTzxR83kRsRc");
 
 #[program]
@@ -22,15 +29,43 @@ pub mod trade_dot_fun {
     use crate::instructions::distribute_zbtc_reward::DistributeZbtcReward;
     use crate::instructions::initialize_vault_data::InitializeVaultData;
     use crate::instructions::initialize_user_data::InitializeUserData;
+    use crate::instructions::initialize_config::InitializeConfig;
+    use crate::instructions::queue_action::QueueAction;
+    use crate::instructions::execute_action::ExecuteAction;
+    use crate::instructions::cancel_action::CancelAction;
+    use crate::instructions::accept_admin::AcceptAdmin;
+    use crate::instructions::initialize_role_registry::InitializeRoleRegistry;
+    use crate::instructions::grant_role::GrantRole;
+    use crate::instructions::revoke_role::RevokeRole;
+    use crate::instructions::set_pause::SetPause;
+    use crate::instructions::migrate_user_data::MigrateUserData;
+    use crate::instructions::close_deposit_receipt::CloseDepositReceipt;
+    use crate::instructions::deposit_native_sol::NativeSolSwap;
+    use crate::instructions::deposit_inf::InfDeposit;
+    use crate::instructions::set_deposit_limits::SetDepositLimits;
+    use crate::instructions::bind_referrer::BindReferrer;
+    use crate::instructions::deposit_for::DepositFor;
+    use crate::instructions::redeem_to_sol::RedeemToSol;
+    use crate::instructions::request_withdrawal::RequestWithdrawal;
+    use crate::instructions::fulfill_withdrawal::FulfillWithdrawal;
+    use crate::instructions::cancel_withdrawal::CancelWithdrawal;
+    use crate::instructions::claim_referral_bonus::ClaimReferralBonus;
 
     use super::*;
 
-    pub fn deposit_sol(ctx: Context<UserSwap>, round_number: u64, data: Vec<u8>) -> Result<()> {
-        instructions::deposit_sol::deposit_sol(ctx, round_number, data)
+    pub fn deposit_sol(
+        ctx: Context<UserSwap>,
+        round_number: u64,
+        data: Vec<u8>,
+        min_inf_out: u64,
+        max_sol_in: u64,
+        lock_duration: i64,
+    ) -> Result<()> {
+        instructions::deposit_sol::deposit_sol(ctx, round_number, data, min_inf_out, max_sol_in, lock_duration)
     }
 
-    pub fn redeem(ctx: Context<Redeem>) -> Result<()> {
-        instructions::redeem::redeem(ctx)
+    pub fn redeem(ctx: Context<Redeem>, sol_amount: u64) -> Result<()> {
+        instructions::redeem::redeem(ctx, sol_amount)
     }
 
     pub fn initialize_round(ctx: Context<InitializeRound>, round_number: u64) -> Result<()> {
@@ -81,4 +116,117 @@ pub mod trade_dot_fun {
     pub fn initialize_user_data(ctx: Context<InitializeUserData>) -> Result<()> {
         instructions::initialize_user_data::initialize_user_data(ctx)
     }
-}
+
+    pub fn initialize_config(
+        ctx: Context<InitializeConfig>,
+        admin: Pubkey,
+        timelock_delay: i64,
+        mints: TokenMints,
+        oracle: OracleConfig,
+    ) -> Result<()> {
+        instructions::initialize_config::initialize_config(ctx, admin, timelock_delay, mints, oracle)
+    }
+
+    pub fn queue_action(ctx: Context<QueueAction>, action: AdminAction) -> Result<()> {
+        instructions::queue_action::queue_action(ctx, action)
+    }
+
+    pub fn execute_action(ctx: Context<ExecuteAction>, action_id: u64) -> Result<()> {
+        instructions::execute_action::execute_action(ctx, action_id)
+    }
+
+    pub fn cancel_action(ctx: Context<CancelAction>, action_id: u64) -> Result<()> {
+        instructions::cancel_action::cancel_action(ctx, action_id)
+    }
+
+    pub fn accept_admin(ctx: Context<AcceptAdmin>) -> Result<()> {
+        instructions::accept_admin::accept_admin(ctx)
+    }
+
+    pub fn initialize_role_registry(ctx: Context<InitializeRoleRegistry>) -> Result<()> {
+        instructions::initialize_role_registry::initialize_role_registry(ctx)
+    }
+
+    pub fn grant_role(ctx: Context<GrantRole>, role: Role, member: Pubkey) -> Result<()> {
+        instructions::grant_role::grant_role(ctx, role, member)
+    }
+
+    pub fn revoke_role(ctx: Context<RevokeRole>, role: Role, member: Pubkey) -> Result<()> {
+        instructions::revoke_role::revoke_role(ctx, role, member)
+    }
+
+    pub fn set_pause(ctx: Context<SetPause>, paused: u8) -> Result<()> {
+        instructions::set_pause::set_pause(ctx, paused)
+    }
+
+    pub fn migrate_user_data(ctx: Context<MigrateUserData>) -> Result<()> {
+        instructions::migrate_user_data::migrate_user_data(ctx)
+    }
+
+    pub fn close_deposit_receipt(ctx: Context<CloseDepositReceipt>, deposit_index: u64) -> Result<()> {
+        instructions::close_deposit_receipt::close_deposit_receipt(ctx, deposit_index)
+    }
+
+    pub fn deposit_native_sol(
+        ctx: Context<NativeSolSwap>,
+        round_number: u64,
+        amount: u64,
+        data: Vec<u8>,
+        min_inf_out: u64,
+        lock_duration: i64,
+    ) -> Result<()> {
+        instructions::deposit_native_sol::deposit_native_sol(ctx, round_number, amount, data, min_inf_out, lock_duration)
+    }
+
+    pub fn deposit_inf(
+        ctx: Context<InfDeposit>,
+        round_number: u64,
+        amount: u64,
+        lock_duration: i64,
+    ) -> Result<()> {
+        instructions::deposit_inf::deposit_inf(ctx, round_number, amount, lock_duration)
+    }
+
+    pub fn set_deposit_limits(ctx: Context<SetDepositLimits>, limits: DepositLimits) -> Result<()> {
+        instructions::set_deposit_limits::set_deposit_limits(ctx, limits)
+    }
+
+    pub fn bind_referrer(ctx: Context<BindReferrer>, referrer: Pubkey) -> Result<()> {
+        instructions::bind_referrer::bind_referrer(ctx, referrer)
+    }
+
+    pub fn deposit_for(
+        ctx: Context<DepositFor>,
+        round_number: u64,
+        data: Vec<u8>,
+        min_inf_out: u64,
+        max_sol_in: u64,
+    ) -> Result<()> {
+        instructions::deposit_for::deposit_for(ctx, round_number, data, min_inf_out, max_sol_in)
+    }
+
+    pub fn redeem_to_sol(
+        ctx: Context<RedeemToSol>,
+        sol_amount: u64,
+        data: Vec<u8>,
+        min_sol_out: u64,
+    ) -> Result<()> {
+        instructions::redeem_to_sol::redeem_to_sol(ctx, sol_amount, data, min_sol_out)
+    }
+
+    pub fn request_withdrawal(ctx: Context<RequestWithdrawal>, sol_amount: u64) -> Result<()> {
+        instructions::request_withdrawal::request_withdrawal(ctx, sol_amount)
+    }
+
+    pub fn fulfill_withdrawal(ctx: Context<FulfillWithdrawal>) -> Result<()> {
+        instructions::fulfill_withdrawal::fulfill_withdrawal(ctx)
+    }
+
+    pub fn cancel_withdrawal(ctx: Context<CancelWithdrawal>) -> Result<()> {
+        instructions::cancel_withdrawal::cancel_withdrawal(ctx)
+    }
+
+    pub fn claim_referral_bonus(ctx: Context<ClaimReferralBonus>) -> Result<()> {
+        instructions::claim_referral_bonus::claim_referral_bonus(ctx)
+    }
+}
\ No newline at end of file
@@ -24,6 +24,64 @@ pub struct UserData {
     pub user: Pubkey,
     pub total_sol_deposited: u64,
     pub total_inf_equivalent: u64,  // Total INF equivalent to the SOL deposited (at deposit time)
+    pub deposit_count: u64,  // Number of deposits made, used to seed DepositReceipt PDAs
+    pub chip_remainder: u64,  // Lamports deposited toward the next chip (always < STAKE_RATIO)
+    pub referrer: Option<Pubkey>,  // Set once with bind_referrer, earns a bonus on each deposit
+    pub locks: Vec<LockTranche>,  // Locked deposits, each redeemable once its own lock expires
+}
+
+impl UserData {
+    // Principal still locked at `now`, expired locks count as unlocked
+    pub fn locked_principal(&self, now: i64) -> u64 {
+        self.locks
+            .iter()
+            .filter(|lock| now < lock.expiry)
+            .map(|lock| lock.amount)
+            .sum()
+    }
+
+    // Drops the locks that have expired at `now`
+    pub fn prune_expired_locks(&mut self, now: i64) {
+        self.locks.retain(|lock| now < lock.expiry);
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct LockTranche {
+    pub amount: u64,  // Principal locked by deposits sharing this expiry
+    pub expiry: i64,  // Timestamp at which amount becomes redeemable
+}
+
+#[account]
+pub struct Referrer {
+    pub referrer: Pubkey,
+    pub referee_count: u64,
+    pub referred_principal_sol: u64,  // SOL (equivalent) deposited by referees
+    pub bonus_chips: u64,  // CHIP earned by the referrer as referral bonus
+    pub pending_bonus_chips: u64,  // Part of bonus_chips not minted yet, see claim_referral_bonus
+    pub bump: u8,
+}
+
+#[account]
+pub struct WithdrawalTicket {
+    pub user: Pubkey,
+    pub sol_amount: u64,  // Principal to redeem once the round closes
+    pub round_number: u64,  // Round that was active when the withdrawal was requested
+    pub requested_at: i64,
+    pub bump: u8,
+}
+
+#[account]
+pub struct DepositReceipt {
+    pub user: Pubkey,
+    pub deposit_index: u64,  // UserData.deposit_count at the time of the deposit
+    pub round_number: u64,
+    pub sol_deposited: u64,
+    pub inf_received: u64,
+    pub exchange_rate: u64,  // INF per SOL (scaled by 10^6)
+    pub chips_minted: u64,
+    pub slot: u64,
+    pub timestamp: i64,
 }
 
 #[account]
@@ -33,6 +91,131 @@ pub struct VaultData {
     pub exchange_rate: u64,  // Latest INF/SOL exchange rate (scaled by 10^6)
     pub last_price_update: i64,  // Timestamp of last price update
     pub is_round_active: bool,
+    pub paused: u8,  // Bitmask of PAUSE_* flags from utils
+}
+
+#[account]
+pub struct Config {
+    pub admin: Pubkey,
+    pub pending_admin: Option<Pubkey>,  // Set by a ProposeAdmin action, cleared once accepted
+    pub timelock_delay: i64,  // Seconds a queued AdminAction must wait before execution
+    pub next_action_id: u64,  // Id (and PDA seed) of the next PendingAction
+    pub mints: TokenMints,
+    pub oracle: OracleConfig,
+    pub deposit_limits: DepositLimits,
+    pub referral_bonus_bps: u16,  // Referrer bonus as a share of the depositor's CHIP reward
+    pub accepted_lsts: Vec<AcceptedLst>,  // Input mints deposit_sol accepts besides WSOL
+    pub lock_tiers: Vec<LockTier>,  // Lock durations deposits can opt into, with their CHIP multipliers
+    pub bump: u8,
+}
+
+impl Config {
+    pub fn find_accepted_lst(&self, mint: &Pubkey) -> Option<&AcceptedLst> {
+        self.accepted_lsts.iter().find(|lst| lst.mint == *mint)
+    }
+
+    pub fn find_lock_tier(&self, duration: i64) -> Option<&LockTier> {
+        self.lock_tiers.iter().find(|tier| tier.duration == duration)
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct TokenMints {
+    pub wsol: Pubkey,  // Deposit input mint
+    pub inf: Pubkey,   // LST held by the vault
+    pub chip: Pubkey,  // Reward token minted on deposit
+    pub zbtc: Pubkey,  // Reward payout mint
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct OracleConfig {
+    pub inf_usd_feed_id: [u8; 32],
+    pub sol_usd_feed_id: [u8; 32],
+    pub inf_max_age: u64,  // Maximum INF/USD price age in seconds
+    pub sol_max_age: u64,  // Maximum SOL/USD price age in seconds
+    pub max_conf_ratio_bps: u16,  // Maximum confidence interval as a share of the price
+    pub max_deposit_deviation_bps: u16,  // Maximum gap between a deposit's swap rate and the oracle rate
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub struct DepositLimits {
+    pub min_deposit: u64,  // Minimum SOL (equivalent) per deposit, in lamports
+    pub max_user_principal: u64,  // Cap on UserData.total_sol_deposited, 0 = no cap
+    pub max_total_principal: u64,  // Cap on VaultData.total_principal_sol, 0 = no cap
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct AcceptedLst {
+    pub mint: Pubkey,
+    pub usd_feed_id: [u8; 32],  // Pyth LST/USD feed used to value deposits in SOL
+    pub max_age: u64,  // Maximum LST/USD price age in seconds
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct LockTier {
+    pub duration: i64,  // Lock duration in seconds
+    pub chip_multiplier_bps: u16,  // CHIP minted for a locked deposit, 10000 = no boost
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug)]
+pub enum AdminAction {
+    ProposeAdmin { new_admin: Pubkey },
+    SetTimelockDelay { delay: i64 },
+    SetMints { mints: TokenMints },
+    SetOracleConfig { oracle: OracleConfig },
+    AddAcceptedLst { lst: AcceptedLst },
+    RemoveAcceptedLst { mint: Pubkey },
+    SetReferralBonus { bps: u16 },
+    SetLockTiers { tiers: Vec<LockTier> },
+}
+
+impl AdminAction {
+    // Borsh size of the largest variant: 1 (variant tag) + 4 * 32 (TokenMints)
+    pub const MAX_SIZE: usize = 1 + 4 * 32;
+}
+
+#[account]
+pub struct PendingAction {
+    pub action_id: u64,
+    pub action: AdminAction,
+    pub proposer: Pubkey,  // Receives the rent back once executed or cancelled
+    pub queued_at: i64,
+    pub eta: i64,
+    pub bump: u8,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Role {
+    RoundOperator,  // Initializes, starts and closes rounds
+    Distributor,    // Pays out round rewards
+    Guardian,       // Can pause the protocol but not unpause it
+    Treasurer,      // Manages treasury funds
+}
+
+impl Role {
+    pub fn mask(self) -> u8 {
+        1 << (self as u8)
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub struct RoleMember {
+    pub member: Pubkey,
+    pub roles: u8,  // Bitmask of Role::mask() values
+}
+
+#[account]
+pub struct RoleRegistry {
+    pub members: Vec<RoleMember>,
+    pub bump: u8,
+}
+
+impl RoleRegistry {
+    pub fn has_role(&self, member: &Pubkey, role: Role) -> bool {
+        self.members
+            .iter()
+            .any(|m| m.member == *member && m.roles & role.mask() != 0)
+    }
 }
 
 #[account]
@@ -0,0 +1,23 @@
+// Address sets per cluster, picked by the `localnet`, `devnet` or `mainnet` cargo feature.
+// Builds without any of these features use the mainnet set.
+
+#[cfg(any(
+    all(feature = "localnet", feature = "devnet"),
+    all(feature = "localnet", feature = "mainnet"),
+    all(feature = "devnet", feature = "mainnet"),
+))]
+compile_error!("Only one of the `localnet`, `devnet` and `mainnet` features can be enabled");
+
+#[cfg(feature = "localnet")]
+pub const CLUSTER: &str = "localnet";
+#[cfg(feature = "devnet")]
+pub const CLUSTER: &str = "devnet";
+#[cfg(not(any(feature = "localnet", feature = "devnet")))]
+pub const CLUSTER: &str = "mainnet";
+
+// Jupiter aggregator v6. Localnet builds point at the stand-in swap program that the
+// test validator loads at its own address instead.
+#[cfg(feature = "localnet")]
+pub const JUPITER_PROGRAM_ID: &str = "C9reGqiyEn9n19D8LgFy2BLAY2eCbWH1mAdWSgV1LquJ";
+#[cfg(not(feature = "localnet"))]
+pub const JUPITER_PROGRAM_ID: &str = "JUP6LkbZbjS1jKKwapdHNy74zcZ3tLUZoi5QNyVTaV4";
\ No newline at end of file
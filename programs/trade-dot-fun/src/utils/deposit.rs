@@ -0,0 +1,689 @@
+// Shared steps of the deposit instructions: round, slippage and oracle checks,
+// and the accounting / CHIP minting that follows once the vault holds the INF.
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, MintTo, TokenAccount};
+use pyth_solana_receiver_sdk::price_update::{Price, PriceUpdateV2};
+
+use crate::{
+    debug_msg,
+    errors::{DepositError, LockError, OracleError, ReferralError, RoundError, SwapError},
+    events::{Deposited, ReferralRewarded},
+    instructions::initialize_user_data::init_user_data,
+    state::{AcceptedLst, Config, DepositLimits, DepositReceipt, LockTranche, OracleConfig,
+            Referrer, Round, RoundState, UserData, VaultData},
+    utils::{find_vault_authority_pda, get_checked_price, BPS_DENOMINATOR, MAX_LOCK_TRANCHES,
+            STAKE_RATIO},
+    utils::jupiter::{invoke_jupiter_swap, ExpectedRoute},
+};
+
+// Accounts touched when crediting a deposit
+pub struct DepositAccounts<'a, 'info> {
+    pub user: Pubkey,
+    pub round: &'a mut Account<'info, Round>,
+    pub user_data: &'a mut Account<'info, UserData>,
+    pub vault_data: &'a mut Account<'info, VaultData>,
+    pub deposit_receipt: Option<&'a mut Account<'info, DepositReceipt>>,
+    pub reward_mint: AccountInfo<'info>,
+    pub user_reward_token_account: AccountInfo<'info>,
+    pub vault_authority: AccountInfo<'info>,
+    pub reward_mint_program: AccountInfo<'info>,
+    // Required when user_data.referrer is set
+    pub referrer_stats: Option<&'a mut Account<'info, Referrer>>,
+    // The referrer's CHIP account, without it the bonus is left for the referrer to claim
+    pub referrer_reward_token_account: Option<&'a InterfaceAccount<'info, TokenAccount>>,
+}
+
+// Helper function to check that deposits are accepted for the given round
+pub fn check_deposit_round(round: &Round, round_number: u64) -> Result<()> {
+    require!(
+        round.state != RoundState::Closed,
+        RoundError::InvalidRoundState
+    );
+    require!(
+        round.round_number == round_number,
+        RoundError::InvalidRoundNumber
+    );
+    Ok(())
+}
+
+// Helper function to enforce the user's slippage bounds on the measured swap deltas
+pub fn check_slippage(
+    sol_deposited: u64,
+    inf_received: u64,
+    max_sol_in: u64,
+    min_inf_out: u64,
+) -> Result<()> {
+    require!(
+        sol_deposited <= max_sol_in && inf_received >= min_inf_out,
+        SwapError::SlippageExceeded
+    );
+    Ok(())
+}
+
+// Helper function to run a deposit's Jupiter route from `source` into the vault, signed by
+// `authority`, returning the measured (input spent, INF received)
+pub fn swap_into_vault<'info>(
+    jupiter_program: &Pubkey,
+    remaining_accounts: &[AccountInfo],
+    data: Vec<u8>,
+    authority: Pubkey,
+    source: &mut InterfaceAccount<'info, TokenAccount>,
+    vault_token_account: &mut InterfaceAccount<'info, TokenAccount>,
+) -> Result<(u64, u64)> {
+    let source_balance_before = source.amount;
+    let vault_balance_before = vault_token_account.amount;
+
+    invoke_jupiter_swap(
+        jupiter_program,
+        remaining_accounts,
+        data,
+        &ExpectedRoute {
+            authority,
+            source: source.key(),
+            destination: vault_token_account.key(),
+        },
+        &[],
+    )?;
+
+    debug_msg!("Getting updated account balances");
+    source.reload()?;
+    vault_token_account.reload()?;
+
+    // Verify the input decreased and INF increased
+    let input_spent = source_balance_before
+        .checked_sub(source.amount)
+        .filter(|spent| *spent > 0)
+        .ok_or(SwapError::InvalidSwapDirection)?;
+    let inf_received = vault_token_account.amount
+        .checked_sub(vault_balance_before)
+        .filter(|received| *received > 0)
+        .ok_or(SwapError::InvalidSwapDirection)?;
+    debug_msg!("Input spent: {} INF received: {}", input_spent, inf_received);
+    Ok((input_spent, inf_received))
+}
+
+// Helper function to value a swap input in lamports: WSOL as is, accepted LSTs through
+// their Pyth feed, which must then be passed as `price_update_lst`
+pub fn get_input_sol_value(
+    config: &Config,
+    input_mint: &InterfaceAccount<Mint>,
+    price_update_lst: Option<&Account<PriceUpdateV2>>,
+    price_update_sol: &PriceUpdateV2,
+    input_spent: u64,
+) -> Result<u64> {
+    let sol_value = if input_mint.key() == config.mints.wsol {
+        input_spent
+    } else {
+        let lst = config.find_accepted_lst(&input_mint.key())
+            .ok_or(SwapError::InvalidInputMint)?;
+        let price_update_lst = price_update_lst.ok_or(OracleError::MissingPriceUpdate)?;
+        get_lst_sol_value(
+            &config.oracle,
+            lst,
+            price_update_lst,
+            price_update_sol,
+            input_spent,
+            input_mint.decimals,
+        )?
+    };
+    require!(sol_value > 0, SwapError::ZeroAmount);
+    debug_msg!("Input spent: {} SOL equivalent: {}", input_spent, sol_value);
+    Ok(sol_value)
+}
+
+// Helper function to read the Pyth INF/SOL rate as a (SOL price, INF price) pair,
+// both normalized to the same exponent so that INF per SOL = .0 / .1
+pub fn get_oracle_inf_per_sol(
+    oracle: &OracleConfig,
+    price_update_inf: &PriceUpdateV2,
+    price_update_sol: &PriceUpdateV2,
+) -> Result<(u128, u128)> {
+    let inf_price = get_checked_price(
+        price_update_inf,
+        &oracle.inf_usd_feed_id,
+        oracle.inf_max_age,
+        oracle.max_conf_ratio_bps,
+    )?;
+    let sol_price = get_checked_price(
+        price_update_sol,
+        &oracle.sol_usd_feed_id,
+        oracle.sol_max_age,
+        oracle.max_conf_ratio_bps,
+    )?;
+
+    normalize_prices(&sol_price, &inf_price)
+}
+
+// Helper function to bring two Pyth prices to a common exponent
+fn normalize_prices(a: &Price, b: &Price) -> Result<(u128, u128)> {
+    let mut a_value = Some(a.price as u128);
+    let mut b_value = Some(b.price as u128);
+    let exponent_gap = a.exponent - b.exponent;
+    if exponent_gap >= 0 {
+        a_value = a_value.and_then(|x| x.checked_mul(10u128.checked_pow(exponent_gap as u32)?));
+    } else {
+        b_value = b_value.and_then(|x| x.checked_mul(10u128.checked_pow(exponent_gap.unsigned_abs())?));
+    }
+    Ok(a_value
+        .zip(b_value)
+        .ok_or(crate::errors::OracleError::InvalidPrice)?)
+}
+
+// Native SOL decimals, principal is tracked in lamports
+pub const SOL_DECIMALS: u8 = 9;
+
+// Helper function to value `amount` of an accepted LST in lamports using its Pyth feed and SOL/USD,
+// `lst_decimals` being the decimals of the LST mint
+pub fn get_lst_sol_value(
+    oracle: &OracleConfig,
+    lst: &AcceptedLst,
+    price_update_lst: &PriceUpdateV2,
+    price_update_sol: &PriceUpdateV2,
+    amount: u64,
+    lst_decimals: u8,
+) -> Result<u64> {
+    let lst_price = get_checked_price(
+        price_update_lst,
+        &lst.usd_feed_id,
+        lst.max_age,
+        oracle.max_conf_ratio_bps,
+    )?;
+    let sol_price = get_checked_price(
+        price_update_sol,
+        &oracle.sol_usd_feed_id,
+        oracle.sol_max_age,
+        oracle.max_conf_ratio_bps,
+    )?;
+    lst_amount_to_lamports(&lst_price, &sol_price, amount, lst_decimals)
+}
+
+// Helper function to value `amount` of INF in lamports using its Pyth feed and SOL/USD,
+// `inf_decimals` being the decimals of the INF mint
+pub fn get_inf_sol_value(
+    oracle: &OracleConfig,
+    price_update_inf: &PriceUpdateV2,
+    price_update_sol: &PriceUpdateV2,
+    amount: u64,
+    inf_decimals: u8,
+) -> Result<u64> {
+    let inf_price = get_checked_price(
+        price_update_inf,
+        &oracle.inf_usd_feed_id,
+        oracle.inf_max_age,
+        oracle.max_conf_ratio_bps,
+    )?;
+    let sol_price = get_checked_price(
+        price_update_sol,
+        &oracle.sol_usd_feed_id,
+        oracle.sol_max_age,
+        oracle.max_conf_ratio_bps,
+    )?;
+    lst_amount_to_lamports(&inf_price, &sol_price, amount, inf_decimals)
+}
+
+// Helper function to convert LST base units to lamports at the LST/SOL price ratio
+fn lst_amount_to_lamports(
+    lst_price: &Price,
+    sol_price: &Price,
+    amount: u64,
+    lst_decimals: u8,
+) -> Result<u64> {
+    let (mut lst_value, mut sol_value) = normalize_prices(lst_price, sol_price)?;
+
+    // Rescale from the LST's decimals to lamports
+    let decimals_gap = SOL_DECIMALS as i32 - lst_decimals as i32;
+    let decimals_factor = 10u128.checked_pow(decimals_gap.unsigned_abs());
+    if decimals_gap >= 0 {
+        lst_value = decimals_factor
+            .and_then(|f| lst_value.checked_mul(f))
+            .ok_or(crate::errors::OracleError::InvalidPrice)?;
+    } else {
+        sol_value = decimals_factor
+            .and_then(|f| sol_value.checked_mul(f))
+            .ok_or(crate::errors::OracleError::InvalidPrice)?;
+    }
+
+    Ok((amount as u128)
+        .checked_mul(lst_value)
+        .and_then(|x| x.checked_div(sol_value))
+        .and_then(|x| u64::try_from(x).ok())
+        .ok_or(crate::errors::OracleError::InvalidPrice)?)
+}
+
+// Helper function to reject a swap whose INF-per-SOL rate is too far from the Pyth rate
+pub fn check_deposit_rate(
+    oracle: &OracleConfig,
+    price_update_inf: &PriceUpdateV2,
+    price_update_sol: &PriceUpdateV2,
+    sol_deposited: u64,
+    inf_received: u64,
+) -> Result<()> {
+    let (sol_value, inf_value) = get_oracle_inf_per_sol(oracle, price_update_inf, price_update_sol)?;
+
+    // Both rates are INF per SOL scaled by 10^6
+    let scale_factor: u128 = 1_000_000;
+    let oracle_rate = sol_value
+        .checked_mul(scale_factor)
+        .and_then(|x| x.checked_div(inf_value))
+        .ok_or(crate::errors::OracleError::InvalidPrice)?;
+
+    let swap_rate = (inf_received as u128)
+        .checked_mul(scale_factor)
+        .and_then(|x| x.checked_div(sol_deposited as u128))
+        .ok_or(crate::errors::OracleError::InvalidPrice)?;
+
+    debug_msg!("Swap rate: {} oracle rate: {} (INF per SOL, scaled by 10^6)", swap_rate, oracle_rate);
+
+    let deviation = swap_rate.abs_diff(oracle_rate);
+    require!(
+        deviation.checked_mul(BPS_DENOMINATOR as u128).unwrap()
+            <= oracle_rate.checked_mul(oracle.max_deposit_deviation_bps as u128).unwrap(),
+        SwapError::OracleRateDeviation
+    );
+    Ok(())
+}
+
+// Helper function to enforce the configured minimum deposit and principal caps
+pub fn check_deposit_limits(
+    limits: &DepositLimits,
+    user_data: &UserData,
+    vault_data: &VaultData,
+    sol_deposited: u64,
+) -> Result<()> {
+    require!(
+        sol_deposited >= limits.min_deposit,
+        DepositError::BelowMinimumDeposit
+    );
+    if limits.max_user_principal > 0 {
+        let user_principal = user_data.total_sol_deposited
+            .checked_add(sol_deposited)
+            .unwrap();
+        require!(
+            user_principal <= limits.max_user_principal,
+            DepositError::UserCapExceeded
+        );
+    }
+    if limits.max_total_principal > 0 {
+        let total_principal = vault_data.total_principal_sol
+            .checked_add(sol_deposited)
+            .unwrap();
+        require!(
+            total_principal <= limits.max_total_principal,
+            DepositError::VaultCapExceeded
+        );
+    }
+    Ok(())
+}
+
+// Helper function to split a deposit's `reward_amount` chips into the chips minted to the
+// depositor, boosted by the lock multiplier, and the referrer's bonus on those chips
+pub fn get_deposit_chips(
+    reward_amount: u64,
+    chip_multiplier_bps: u64,
+    referral_bonus_bps: u16,
+) -> (u64, u64) {
+    // Locked deposits are boosted by their tier's multiplier
+    let chips_minted = reward_amount
+        .checked_mul(chip_multiplier_bps)
+        .and_then(|x| x.checked_div(BPS_DENOMINATOR))
+        .unwrap();
+    // The referrer's share follows the boosted chips the depositor actually receives
+    let bonus_chips = chips_minted
+        .checked_mul(referral_bonus_bps as u64)
+        .and_then(|x| x.checked_div(BPS_DENOMINATOR))
+        .unwrap();
+    (chips_minted, bonus_chips)
+}
+
+// Helper function to credit a deposit of `sol_deposited` that put `inf_received` into the vault,
+// locked for `lock_duration` seconds unless it is 0
+pub fn record_deposit(
+    accounts: DepositAccounts,
+    config: &Config,
+    round_number: u64,
+    sol_deposited: u64,
+    inf_received: u64,
+    lock_duration: i64,
+) -> Result<()> {
+    let DepositAccounts {
+        user,
+        round,
+        user_data,
+        vault_data,
+        deposit_receipt,
+        reward_mint,
+        user_reward_token_account,
+        vault_authority,
+        reward_mint_program,
+        referrer_stats,
+        referrer_reward_token_account,
+    } = accounts;
+
+    // A zeroed user key means user_data was just created by init_if_needed
+    if user_data.user == Pubkey::default() {
+        debug_msg!("Creating user data for first deposit");
+        init_user_data(user_data, user);
+    }
+
+    check_deposit_limits(&config.deposit_limits, user_data, vault_data, sol_deposited)?;
+    let chip_multiplier_bps = if lock_duration == 0 {
+        BPS_DENOMINATOR
+    } else {
+        config.find_lock_tier(lock_duration)
+            .ok_or(LockError::InvalidLockDuration)?
+            .chip_multiplier_bps as u64
+    };
+    let clock = Clock::get()?;
+
+    debug_msg!("Updating round statistics");
+    round.total_sol_deposited = round.total_sol_deposited
+        .checked_add(sol_deposited)
+        .unwrap();
+    round.total_inf_received = round.total_inf_received
+        .checked_add(inf_received)
+        .unwrap();
+
+    debug_msg!("Updating user data");
+    user_data.total_sol_deposited = user_data.total_sol_deposited
+        .checked_add(sol_deposited)
+        .unwrap();
+    user_data.total_inf_equivalent = user_data.total_inf_equivalent
+        .checked_add(inf_received)
+        .unwrap();
+    let deposit_index = user_data.deposit_count;
+    user_data.deposit_count = deposit_index
+        .checked_add(1)
+        .unwrap();
+
+    // Each lock keeps its own expiry, so a new lock never holds back principal
+    // locked earlier for a shorter tier
+    let lock_expiry = if lock_duration > 0 {
+        debug_msg!("Locking {} for {} seconds", sol_deposited, lock_duration);
+        let expiry = clock.unix_timestamp.checked_add(lock_duration).unwrap();
+        user_data.prune_expired_locks(clock.unix_timestamp);
+        if let Some(lock) = user_data.locks.iter_mut().find(|lock| lock.expiry == expiry) {
+            lock.amount = lock.amount
+                .checked_add(sol_deposited)
+                .unwrap();
+        } else {
+            require!(user_data.locks.len() < MAX_LOCK_TRANCHES, LockError::TooManyLocks);
+            user_data.locks.push(LockTranche {
+                amount: sol_deposited,
+                expiry,
+            });
+        }
+        expiry
+    } else {
+        0
+    };
+
+    debug_msg!("Updating vault data");
+    vault_data.total_principal_sol = vault_data.total_principal_sol
+        .checked_add(sol_deposited)
+        .unwrap();
+
+    // Calculate reward amount (1 reward token per 0.001 SOL), carrying the
+    // remainder so split deposits earn as many chips as a single one
+    let chip_lamports = user_data.chip_remainder
+        .checked_add(sol_deposited)
+        .unwrap();
+    let reward_amount = chip_lamports
+        .checked_div(STAKE_RATIO)
+        .unwrap();
+    user_data.chip_remainder = chip_lamports
+        .checked_rem(STAKE_RATIO)
+        .unwrap();
+    let (chips_minted, bonus_chips) =
+        get_deposit_chips(reward_amount, chip_multiplier_bps, config.referral_bonus_bps);
+
+    debug_msg!("Minting reward tokens");
+    let (_, bump) = find_vault_authority_pda();
+    let vault_authority_seeds = &[b"vault_authority".as_ref(), &[bump]];
+    let signer_seeds = &[&vault_authority_seeds[..]];
+
+    let cpi_accounts = MintTo {
+        mint: reward_mint.clone(),
+        to: user_reward_token_account,
+        authority: vault_authority.clone(),
+    };
+    let cpi_ctx = CpiContext::new_with_signer(
+        reward_mint_program.clone(),
+        cpi_accounts,
+        signer_seeds
+    );
+    anchor_spl::token_interface::mint_to(cpi_ctx, chips_minted)?;
+
+    // Referral bonus, minted on top of the depositor's chips. The binding is permanent,
+    // so a referrer without a CHIP account must not block the referee's deposits
+    if let Some(referrer) = user_data.referrer {
+        let referrer_stats = referrer_stats.ok_or(ReferralError::MissingReferrerAccounts)?;
+        require_keys_eq!(
+            referrer_stats.referrer,
+            referrer,
+            ReferralError::InvalidReferrerAccounts
+        );
+
+        let deferred = referrer_reward_token_account.is_none();
+        if let Some(referrer_reward_token_account) = referrer_reward_token_account {
+            require!(
+                referrer_reward_token_account.owner == referrer
+                    && referrer_reward_token_account.mint == reward_mint.key(),
+                ReferralError::InvalidReferrerAccounts
+            );
+            if bonus_chips > 0 {
+                debug_msg!("Minting referral bonus to {}", referrer);
+                let cpi_accounts = MintTo {
+                    mint: reward_mint,
+                    to: referrer_reward_token_account.to_account_info(),
+                    authority: vault_authority,
+                };
+                let cpi_ctx = CpiContext::new_with_signer(
+                    reward_mint_program,
+                    cpi_accounts,
+                    signer_seeds
+                );
+                anchor_spl::token_interface::mint_to(cpi_ctx, bonus_chips)?;
+            }
+        } else {
+            debug_msg!("Crediting referral bonus to {} for a later claim", referrer);
+            referrer_stats.pending_bonus_chips = referrer_stats.pending_bonus_chips
+                .checked_add(bonus_chips)
+                .unwrap();
+        }
+
+        referrer_stats.referred_principal_sol = referrer_stats.referred_principal_sol
+            .checked_add(sol_deposited)
+            .unwrap();
+        referrer_stats.bonus_chips = referrer_stats.bonus_chips
+            .checked_add(bonus_chips)
+            .unwrap();
+
+        emit!(ReferralRewarded {
+            referrer,
+            user,
+            sol_deposited,
+            bonus_chips,
+            deferred,
+            timestamp: clock.unix_timestamp,
+        });
+    }
+
+    debug_msg!("SOL decreased by: {}", sol_deposited);
+    debug_msg!("INF increased by: {}", inf_received);
+    debug_msg!("Reward tokens minted: {} (1 token per 0.001 SOL, {} bps)", chips_minted, chip_multiplier_bps);
+    debug_msg!("Lamports carried toward the next chip: {}", user_data.chip_remainder);
+    debug_msg!("Total vault principal SOL: {}", vault_data.total_principal_sol);
+
+    // Calculate and log exchange rate (INF per SOL)
+    let exchange_rate = inf_received
+        .checked_mul(1_000_000) // Scale by 10^6 for precision
+        .and_then(|x| x.checked_div(sol_deposited))
+        .unwrap_or(0);
+    debug_msg!("Exchange rate: {} INF per SOL", exchange_rate);
+
+    debug_msg!("Round {} statistics:", round.round_number);
+    debug_msg!("Total SOL deposited: {}", round.total_sol_deposited);
+    debug_msg!("Total INF received: {}", round.total_inf_received);
+
+    if let Some(deposit_receipt) = deposit_receipt {
+        deposit_receipt.user = user;
+        deposit_receipt.deposit_index = deposit_index;
+        deposit_receipt.round_number = round_number;
+        deposit_receipt.sol_deposited = sol_deposited;
+        deposit_receipt.inf_received = inf_received;
+        deposit_receipt.exchange_rate = exchange_rate;
+        deposit_receipt.chips_minted = chips_minted;
+        deposit_receipt.slot = clock.slot;
+        deposit_receipt.timestamp = clock.unix_timestamp;
+        debug_msg!("Deposit receipt {} recorded", deposit_index);
+    }
+
+    emit!(Deposited {
+        user,
+        round_number,
+        sol_deposited,
+        inf_received,
+        exchange_rate,
+        chips_minted,
+        lock_expiry,
+        total_principal_sol: vault_data.total_principal_sol,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "Deposit: user={} round={} sol={} inf={} chips={}",
+        user,
+        round_number,
+        sol_deposited,
+        inf_received,
+        chips_minted,
+    );
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::testing::{
+        install_clock, oracle_config, price_update, INF_FEED_ID, NOW, SOL_FEED_ID,
+    };
+
+    fn price(price: i64, exponent: i32) -> Price {
+        Price { price, conf: 0, exponent, publish_time: 0 }
+    }
+
+    #[test]
+    fn lst_value_with_sol_decimals() {
+        // 1.1 LST per SOL: LST at $165, SOL at $150
+        let lst_price = price(16_500_000_000, -8);
+        let sol_price = price(15_000_000_000, -8);
+        let value = lst_amount_to_lamports(&lst_price, &sol_price, 2_000_000_000, 9);
+        assert_eq!(value.unwrap(), 2_200_000_000);
+    }
+
+    #[test]
+    fn lst_value_scales_by_mint_decimals() {
+        let lst_price = price(16_500_000_000, -8);
+        let sol_price = price(15_000_000_000, -8);
+        // 2 LST in 6 and 12 decimal mints are worth the same 2.2 SOL
+        let six_decimals = lst_amount_to_lamports(&lst_price, &sol_price, 2_000_000, 6);
+        assert_eq!(six_decimals.unwrap(), 2_200_000_000);
+        let twelve_decimals = lst_amount_to_lamports(&lst_price, &sol_price, 2_000_000_000_000, 12);
+        assert_eq!(twelve_decimals.unwrap(), 2_200_000_000);
+    }
+
+    #[test]
+    fn lst_value_rejects_overflow() {
+        let value = lst_amount_to_lamports(&price(i64::MAX, 0), &price(1, -8), u64::MAX, 0);
+        assert!(value.is_err());
+    }
+
+    #[test]
+    fn normalize_prices_keeps_equal_exponents() {
+        let (a, b) = normalize_prices(&price(150, -8), &price(165, -8)).unwrap();
+        assert_eq!((a, b), (150, 165));
+    }
+
+    #[test]
+    fn normalize_prices_scales_the_larger_exponent() {
+        let (a, b) = normalize_prices(&price(150_000_000, -6), &price(16_500_000_000, -8)).unwrap();
+        assert_eq!((a, b), (15_000_000_000, 16_500_000_000));
+        let (a, b) = normalize_prices(&price(16_500_000_000, -8), &price(150_000_000, -6)).unwrap();
+        assert_eq!((a, b), (16_500_000_000, 15_000_000_000));
+    }
+
+    #[test]
+    fn normalize_prices_rejects_overflowing_gap() {
+        assert!(normalize_prices(&price(1, 0), &price(1, -40)).is_err());
+        assert!(normalize_prices(&price(i64::MAX, 0), &price(1, -30)).is_err());
+    }
+
+    // INF at $200 and SOL at $100, 0.5 INF per SOL
+    fn check_rate(sol_price: (i64, i32), sol_deposited: u64, inf_received: u64) -> Result<()> {
+        install_clock();
+        check_deposit_rate(
+            &oracle_config(),
+            &price_update(INF_FEED_ID, 20_000_000_000, -8),
+            &price_update(SOL_FEED_ID, sol_price.0, sol_price.1),
+            sol_deposited,
+            inf_received,
+        )
+    }
+
+    #[test]
+    fn deposit_rate_within_deviation() {
+        assert!(check_rate((10_000_000_000, -8), 1_000_000_000, 500_000_000).is_ok());
+        // Exactly 1% either side of the oracle rate
+        assert!(check_rate((10_000_000_000, -8), 1_000_000_000, 505_000_000).is_ok());
+        assert!(check_rate((10_000_000_000, -8), 1_000_000_000, 495_000_000).is_ok());
+    }
+
+    #[test]
+    fn deposit_rate_outside_deviation() {
+        let deviation: Error = SwapError::OracleRateDeviation.into();
+        // Rates are truncated to 10^-6 INF per SOL, one unit past 1% is rejected
+        let above = check_rate((10_000_000_000, -8), 1_000_000_000, 505_001_000);
+        assert_eq!(above.unwrap_err(), deviation);
+        let below = check_rate((10_000_000_000, -8), 1_000_000_000, 494_999_999);
+        assert_eq!(below.unwrap_err(), deviation);
+    }
+
+    #[test]
+    fn deposit_rate_with_mismatched_exponents() {
+        // SOL at $100 with exponent -5 instead of -8
+        assert!(check_rate((10_000_000, -5), 1_000_000_000, 500_000_000).is_ok());
+        assert!(check_rate((10_000_000, -5), 1_000_000_000, 600_000_000).is_err());
+    }
+
+    #[test]
+    fn deposit_rate_rejects_stale_or_wrong_feed() {
+        install_clock();
+        let oracle = oracle_config();
+        let price_update_inf = price_update(INF_FEED_ID, 20_000_000_000, -8);
+        let mut stale_sol = price_update(SOL_FEED_ID, 10_000_000_000, -8);
+        stale_sol.price_message.publish_time = NOW - oracle.sol_max_age as i64 - 1;
+        let stale =
+            check_deposit_rate(&oracle, &price_update_inf, &stale_sol, 1_000_000_000, 500_000_000);
+        assert!(stale.is_err());
+
+        let wrong_feed = price_update(INF_FEED_ID, 10_000_000_000, -8);
+        let mismatched =
+            check_deposit_rate(&oracle, &price_update_inf, &wrong_feed, 1_000_000_000, 500_000_000);
+        assert!(mismatched.is_err());
+    }
+    #[test]
+    fn unlocked_deposit_chips() {
+        // 5 chips, no boost, 10% referral bonus
+        assert_eq!(get_deposit_chips(5, BPS_DENOMINATOR, 1_000), (5, 0));
+        assert_eq!(get_deposit_chips(50, BPS_DENOMINATOR, 1_000), (50, 5));
+        assert_eq!(get_deposit_chips(50, BPS_DENOMINATOR, 0), (50, 0));
+    }
+
+    #[test]
+    fn locked_deposit_with_a_referrer_boosts_the_bonus() {
+        // 2x lock tier and 10% referral bonus: the referrer gets 10% of the boosted chips
+        assert_eq!(get_deposit_chips(50, 20_000, 1_000), (100, 10));
+        // 1.5x tier, rounded down
+        assert_eq!(get_deposit_chips(33, 15_000, 1_000), (49, 4));
+    }
+}
\ No newline at end of file
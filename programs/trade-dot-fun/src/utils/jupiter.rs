@@ -0,0 +1,327 @@
+// Validation of the Jupiter route instructions we forward. Only the route
+// variants below are accepted, and their source, destination and transfer
+// authority must be the accounts the calling instruction expects.
+use anchor_lang::{
+    prelude::*,
+    solana_program::{instruction::Instruction, program::invoke_signed},
+};
+
+use crate::{debug_msg, errors::JupiterError};
+
+// Anchor discriminators of the accepted Jupiter v6 instructions
+pub const ROUTE_DISCRIMINATOR: [u8; 8] = [229, 23, 203, 151, 122, 227, 173, 42];
+pub const SHARED_ACCOUNTS_ROUTE_DISCRIMINATOR: [u8; 8] = [193, 32, 155, 51, 65, 214, 156, 129];
+pub const EXACT_OUT_ROUTE_DISCRIMINATOR: [u8; 8] = [208, 51, 239, 151, 123, 43, 237, 92];
+pub const SHARED_ACCOUNTS_EXACT_OUT_ROUTE_DISCRIMINATOR: [u8; 8] = [176, 209, 105, 168, 154, 125, 69, 62];
+
+// Positions of the accounts we check in a route's account list
+struct RouteLayout {
+    authority: usize,
+    source: usize,
+    destination: usize,
+    // Optional destination override, set to the Jupiter program ID when unused
+    destination_override: Option<usize>,
+    min_accounts: usize,
+}
+
+const ROUTE_LAYOUT: RouteLayout = RouteLayout {
+    authority: 1,
+    source: 2,
+    destination: 3,
+    destination_override: Some(4),
+    min_accounts: 9,
+};
+
+const EXACT_OUT_ROUTE_LAYOUT: RouteLayout = RouteLayout {
+    authority: 1,
+    source: 2,
+    destination: 3,
+    destination_override: Some(4),
+    min_accounts: 11,
+};
+
+// shared_accounts_route and shared_accounts_exact_out_route
+const SHARED_ACCOUNTS_LAYOUT: RouteLayout = RouteLayout {
+    authority: 2,
+    source: 3,
+    destination: 6,
+    destination_override: None,
+    min_accounts: 13,
+};
+
+// Accounts a route must use
+pub struct ExpectedRoute {
+    pub authority: Pubkey,
+    pub source: Pubkey,
+    pub destination: Pubkey,
+}
+
+fn route_layout(data: &[u8]) -> Result<&'static RouteLayout> {
+    let discriminator: [u8; 8] = data
+        .get(..8)
+        .and_then(|d| d.try_into().ok())
+        .ok_or(JupiterError::InvalidRouteInstruction)?;
+    match discriminator {
+        ROUTE_DISCRIMINATOR => Ok(&ROUTE_LAYOUT),
+        EXACT_OUT_ROUTE_DISCRIMINATOR => Ok(&EXACT_OUT_ROUTE_LAYOUT),
+        SHARED_ACCOUNTS_ROUTE_DISCRIMINATOR | SHARED_ACCOUNTS_EXACT_OUT_ROUTE_DISCRIMINATOR => {
+            Ok(&SHARED_ACCOUNTS_LAYOUT)
+        }
+        _ => err!(JupiterError::InvalidRouteInstruction),
+    }
+}
+
+// Helper function to check a route's instruction and accounts before forwarding it
+pub fn validate_jupiter_route(
+    jupiter_program: &Pubkey,
+    accounts: &[AccountInfo],
+    data: &[u8],
+    expected: &ExpectedRoute,
+) -> Result<()> {
+    let layout = route_layout(data)?;
+    require!(
+        accounts.len() >= layout.min_accounts,
+        JupiterError::InvalidRouteAccounts
+    );
+
+    require_keys_eq!(
+        *accounts[layout.authority].key,
+        expected.authority,
+        JupiterError::InvalidRouteAuthority
+    );
+    require_keys_eq!(
+        *accounts[layout.source].key,
+        expected.source,
+        JupiterError::InvalidRouteSource
+    );
+
+    let destination = match layout.destination_override {
+        Some(index) if accounts[index].key != jupiter_program => accounts[index].key,
+        _ => accounts[layout.destination].key,
+    };
+    require_keys_eq!(
+        *destination,
+        expected.destination,
+        JupiterError::InvalidRouteDestination
+    );
+
+    // The transfer authority is the only signer privilege we pass on
+    require!(
+        accounts
+            .iter()
+            .all(|acc| !acc.is_signer || *acc.key == expected.authority),
+        JupiterError::UnexpectedSigner
+    );
+    Ok(())
+}
+
+// Helper function to validate and forward a Jupiter route, `signer_seeds` is
+// empty unless the transfer authority is a program PDA
+pub fn invoke_jupiter_swap(
+    jupiter_program: &Pubkey,
+    remaining_accounts: &[AccountInfo],
+    data: Vec<u8>,
+    expected: &ExpectedRoute,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    validate_jupiter_route(jupiter_program, remaining_accounts, &data, expected)?;
+    debug_msg!("Jupiter route validated: {} accounts", remaining_accounts.len());
+
+    let accounts: Vec<AccountMeta> = remaining_accounts
+        .iter()
+        .map(|acc| {
+            AccountMeta {
+                pubkey: *acc.key,
+                is_signer: *acc.key == expected.authority,
+                is_writable: acc.is_writable,
+            }
+        })
+        .collect();
+
+    let accounts_infos: Vec<AccountInfo> = remaining_accounts
+        .iter()
+        .map(|acc| AccountInfo { ..acc.clone() })
+        .collect();
+
+    invoke_signed(
+        &Instruction {
+            program_id: *jupiter_program,
+            accounts,
+            data,
+        },
+        &accounts_infos,
+        signer_seeds,
+    )?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Route {
+        jupiter: Pubkey,
+        expected: ExpectedRoute,
+        keys: Vec<Pubkey>,
+        signers: Vec<usize>,
+    }
+
+    impl Route {
+        // A route whose checked positions hold the expected accounts, every other account
+        // (including an unused destination override) is the Jupiter program
+        fn new(layout: &RouteLayout) -> Self {
+            let jupiter = Pubkey::new_unique();
+            let expected = ExpectedRoute {
+                authority: Pubkey::new_unique(),
+                source: Pubkey::new_unique(),
+                destination: Pubkey::new_unique(),
+            };
+            let mut keys = vec![jupiter; layout.min_accounts];
+            keys[layout.authority] = expected.authority;
+            keys[layout.source] = expected.source;
+            keys[layout.destination] = expected.destination;
+            Route { jupiter, expected, keys, signers: vec![layout.authority] }
+        }
+
+        fn validate(&self, data: &[u8]) -> Result<()> {
+            let owner = Pubkey::default();
+            let mut lamports = vec![0u64; self.keys.len()];
+            let mut account_data = vec![[0u8; 0]; self.keys.len()];
+            let accounts: Vec<AccountInfo> = self.keys
+                .iter()
+                .zip(lamports.iter_mut())
+                .zip(account_data.iter_mut())
+                .enumerate()
+                .map(|(i, ((key, lamports), data))| {
+                    let is_signer = self.signers.contains(&i);
+                    AccountInfo::new(key, is_signer, true, lamports, data, &owner, false, 0)
+                })
+                .collect();
+            validate_jupiter_route(&self.jupiter, &accounts, data, &self.expected)
+        }
+    }
+
+    fn data(discriminator: [u8; 8]) -> Vec<u8> {
+        [discriminator.as_ref(), &[0u8; 16]].concat()
+    }
+
+    fn expect_error(result: Result<()>, error: JupiterError) {
+        assert_eq!(result.unwrap_err(), error.into());
+    }
+
+    const LAYOUTS: [([u8; 8], &RouteLayout); 4] = [
+        (ROUTE_DISCRIMINATOR, &ROUTE_LAYOUT),
+        (EXACT_OUT_ROUTE_DISCRIMINATOR, &EXACT_OUT_ROUTE_LAYOUT),
+        (SHARED_ACCOUNTS_ROUTE_DISCRIMINATOR, &SHARED_ACCOUNTS_LAYOUT),
+        (SHARED_ACCOUNTS_EXACT_OUT_ROUTE_DISCRIMINATOR, &SHARED_ACCOUNTS_LAYOUT),
+    ];
+
+    #[test]
+    fn discriminators_match_jupiter_instructions() {
+        use anchor_lang::solana_program::hash::hash;
+        for (name, discriminator) in [
+            ("route", ROUTE_DISCRIMINATOR),
+            ("shared_accounts_route", SHARED_ACCOUNTS_ROUTE_DISCRIMINATOR),
+            ("exact_out_route", EXACT_OUT_ROUTE_DISCRIMINATOR),
+            ("shared_accounts_exact_out_route", SHARED_ACCOUNTS_EXACT_OUT_ROUTE_DISCRIMINATOR),
+        ] {
+            let preimage = format!("global:{}", name);
+            assert_eq!(hash(preimage.as_bytes()).to_bytes()[..8], discriminator, "{}", name);
+        }
+    }
+
+    #[test]
+    fn accepts_each_route_layout() {
+        for (discriminator, layout) in LAYOUTS {
+            let route = Route::new(layout);
+            assert!(route.validate(&data(discriminator)).is_ok());
+            // Trailing accounts past the checked positions are allowed
+            let mut longer = Route::new(layout);
+            longer.keys.push(Pubkey::new_unique());
+            assert!(longer.validate(&data(discriminator)).is_ok());
+        }
+    }
+
+    #[test]
+    fn rejects_unknown_discriminator() {
+        let route = Route::new(&ROUTE_LAYOUT);
+        expect_error(route.validate(&data([0; 8])), JupiterError::InvalidRouteInstruction);
+        let truncated = route.validate(&ROUTE_DISCRIMINATOR[..7]);
+        expect_error(truncated, JupiterError::InvalidRouteInstruction);
+        expect_error(route.validate(&[]), JupiterError::InvalidRouteInstruction);
+    }
+
+    #[test]
+    fn rejects_too_few_accounts() {
+        for (discriminator, layout) in LAYOUTS {
+            let mut route = Route::new(layout);
+            route.keys.pop();
+            expect_error(route.validate(&data(discriminator)), JupiterError::InvalidRouteAccounts);
+        }
+    }
+
+    #[test]
+    fn rejects_wrong_authority() {
+        for (discriminator, layout) in LAYOUTS {
+            let mut route = Route::new(layout);
+            route.keys[layout.authority] = Pubkey::new_unique();
+            expect_error(route.validate(&data(discriminator)), JupiterError::InvalidRouteAuthority);
+        }
+    }
+
+    #[test]
+    fn rejects_wrong_source() {
+        for (discriminator, layout) in LAYOUTS {
+            let mut route = Route::new(layout);
+            route.keys[layout.source] = Pubkey::new_unique();
+            expect_error(route.validate(&data(discriminator)), JupiterError::InvalidRouteSource);
+        }
+    }
+
+    #[test]
+    fn rejects_wrong_destination() {
+        for (discriminator, layout) in LAYOUTS {
+            let mut route = Route::new(layout);
+            route.keys[layout.destination] = Pubkey::new_unique();
+            let result = route.validate(&data(discriminator));
+            expect_error(result, JupiterError::InvalidRouteDestination);
+        }
+    }
+
+    #[test]
+    fn destination_override_replaces_destination() {
+        for (discriminator, layout) in [
+            (ROUTE_DISCRIMINATOR, &ROUTE_LAYOUT),
+            (EXACT_OUT_ROUTE_DISCRIMINATOR, &EXACT_OUT_ROUTE_LAYOUT),
+        ] {
+            let index = layout.destination_override.unwrap();
+
+            // A set override is where the output goes, whatever the destination account is
+            let mut redirected = Route::new(layout);
+            redirected.keys[index] = Pubkey::new_unique();
+            let result = redirected.validate(&data(discriminator));
+            expect_error(result, JupiterError::InvalidRouteDestination);
+
+            let mut overridden = Route::new(layout);
+            overridden.keys[index] = overridden.expected.destination;
+            overridden.keys[layout.destination] = Pubkey::new_unique();
+            assert!(overridden.validate(&data(discriminator)).is_ok());
+        }
+    }
+
+    #[test]
+    fn rejects_extra_signer() {
+        for (discriminator, layout) in LAYOUTS {
+            let mut route = Route::new(layout);
+            route.keys.push(Pubkey::new_unique());
+            route.signers.push(route.keys.len() - 1);
+            expect_error(route.validate(&data(discriminator)), JupiterError::UnexpectedSigner);
+
+            // The authority signing from another position is still only the authority
+            let mut repeated = Route::new(layout);
+            repeated.keys.push(repeated.expected.authority);
+            repeated.signers.push(repeated.keys.len() - 1);
+            assert!(repeated.validate(&data(discriminator)).is_ok());
+        }
+    }
+}
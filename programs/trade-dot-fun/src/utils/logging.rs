@@ -0,0 +1,11 @@
+// Verbose diagnostics (step traces, intermediate values, raw Pyth prices) are only
+// logged when the program is built with the `anchor-debug` feature. Release builds
+// keep the arguments type-checked but skip the msg! syscalls and their formatting.
+#[macro_export]
+macro_rules! debug_msg {
+    ($($arg:tt)*) => {
+        if cfg!(feature = "anchor-debug") {
+            anchor_lang::prelude::msg!($($arg)*);
+        }
+    };
+}
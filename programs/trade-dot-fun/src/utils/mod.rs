@@ -1,46 +1,136 @@
 use anchor_lang::prelude::*;
+use pyth_solana_receiver_sdk::price_update::{Price, PriceUpdateV2};
 use std::str::FromStr;
 
-// Constants for token mints
-pub fn wsol_mint() -> Pubkey {
-    Pubkey::from_str("So11111111111111111111111111111111111111112").unwrap()
+use crate::state::{AcceptedLst, Config, LockTier, OracleConfig, Role, RoleRegistry, VaultData};
+
+// Add constant for stake ratio (0.001 SOL = 1 reward token)
+pub const STAKE_RATIO: u64 = 1_000_000; // 0.001 SOL in lamports
+
+// Basis point denominator used for ratios stored in the config
+pub const BPS_DENOMINATOR: u64 = 10_000;
+
+// Helper function to reject oracle settings that would block or weaken every price read
+pub fn validate_oracle_config(oracle: &OracleConfig) -> Result<()> {
+    require!(
+        oracle.inf_max_age > 0
+            && oracle.sol_max_age > 0
+            && (oracle.max_conf_ratio_bps as u64) <= BPS_DENOMINATOR
+            && oracle.max_deposit_deviation_bps > 0
+            && (oracle.max_deposit_deviation_bps as u64) <= BPS_DENOMINATOR,
+        crate::errors::OracleError::InvalidOracleConfig
+    );
+    Ok(())
 }
 
-pub fn inf_mint() -> Pubkey {
-    Pubkey::from_str("5oVNBeEEQvYi1cX3ir8Dx5n1P7pdxydbGF2X4TxVusJm").unwrap()
+// Helper function to read a Pyth price, rejecting stale, non-positive or low-confidence prices
+pub fn get_checked_price(
+    price_update: &PriceUpdateV2,
+    feed_id: &[u8; 32],
+    maximum_age: u64,
+    max_conf_ratio_bps: u16,
+) -> Result<Price> {
+    let price = price_update.get_price_no_older_than(&Clock::get()?, maximum_age, feed_id)?;
+    require!(price.price > 0, crate::errors::OracleError::InvalidPrice);
+
+    let conf_ratio_bps = (price.conf as u128)
+        .checked_mul(BPS_DENOMINATOR as u128)
+        .and_then(|x| x.checked_div(price.price as u128))
+        .ok_or(crate::errors::OracleError::InvalidPrice)?;
+    require!(
+        conf_ratio_bps <= max_conf_ratio_bps as u128,
+        crate::errors::OracleError::ConfidenceTooWide
+    );
+    Ok(price)
 }
 
-pub fn chip_token_mint() -> Pubkey {
-    Pubkey::from_str("chip6YRCCXMy1uLbGRNErT66aYGdaVsVCQ25VA1LWNN").unwrap()
+pub mod cluster;
+pub mod deposit;
+pub mod jupiter;
+pub mod logging;
+pub mod redeem;
+#[cfg(test)]
+pub mod testing;
+
+// Jupiter program ID for the cluster this build targets
+pub fn jupiter_program_id() -> Pubkey {
+    Pubkey::from_str(cluster::JUPITER_PROGRAM_ID).unwrap()
 }
 
-pub fn zbtc_mint() -> Pubkey {
-    Pubkey::from_str("93a1L7xaEV7vZGt3jNMSQCgGjQx5WFYSm4CrS2s4KBcL").unwrap()
+// Helper function to check if a signer is the admin stored in the config account
+pub fn check_authorized_admin(signer: &Pubkey, config: &Config) -> Result<()> {
+    require!(
+        *signer == config.admin,
+        crate::errors::RoundError::UnauthorizedUser
+    );
+    Ok(())
 }
 
-// Add constant for stake ratio (0.001 SOL = 1 reward token)
-pub const STAKE_RATIO: u64 = 1_000_000; // 0.001 SOL in lamports
+// Upper bound for Config.timelock_delay (30 days)
+pub const MAX_TIMELOCK_DELAY: i64 = 30 * 24 * 60 * 60;
 
-// Pyth price feed ID constants
-pub const INF_USD_PRICE_FEED_ID: &str = "0xf51570985c642c49c2d6e50156390fdba80bb6d5f7fa389d2f012ced4f7d208f";
-pub const SOL_USD_PRICE_FEED_ID: &str = "0xef0d8b6fda2ceba41da15d4095d1da392a0d2f8ed0c6c7bc0f4cfac8c280b56d";
-pub const MAXIMUM_AGE: u64 = 60; // 60 seconds maximum age
+// Maximum number of LSTs Config.accepted_lsts can hold
+pub const MAX_ACCEPTED_LSTS: usize = 8;
 
-// Jupiter program ID
-pub fn jupiter_program_id() -> Pubkey {
-    Pubkey::from_str("JUP6LkbZbjS1jKKwapdHNy74zcZ3tLUZoi5QNyVTaV4").unwrap()
+// Helper function to reject an LST entry that could never be valued or would shadow a core mint
+pub fn validate_accepted_lst(lst: &AcceptedLst, config: &Config) -> Result<()> {
+    require!(
+        lst.max_age > 0 && lst.mint != config.mints.wsol && lst.mint != config.mints.inf,
+        crate::errors::ConfigError::InvalidAcceptedLst
+    );
+    Ok(())
 }
 
-// Authorized pubkey for admin operations
-pub fn authorized_admin() -> Pubkey {
-    Pubkey::from_str("2qKn8e94V8HiemUQJ3vG13zC555TBbxFkmbqgU4tUDXJ").unwrap()
+pub const MAX_LOCK_TIERS: usize = 4;
+// Maximum number of unexpired locks (distinct expiries) a user can hold
+pub const MAX_LOCK_TRANCHES: usize = 8;
+// Highest CHIP multiplier a lock tier can grant (5x)
+pub const MAX_LOCK_MULTIPLIER_BPS: u16 = 50_000;
+
+// Helper function to check a lock tier table before it replaces the current one
+pub fn validate_lock_tiers(tiers: &[LockTier]) -> Result<()> {
+    require!(tiers.len() <= MAX_LOCK_TIERS, crate::errors::LockError::LockTiersFull);
+    for (i, tier) in tiers.iter().enumerate() {
+        require!(
+            tier.duration > 0
+                && (tier.chip_multiplier_bps as u64) >= BPS_DENOMINATOR
+                && tier.chip_multiplier_bps <= MAX_LOCK_MULTIPLIER_BPS
+                && tiers[..i].iter().all(|other| other.duration != tier.duration),
+            crate::errors::LockError::InvalidLockTiers
+        );
+    }
+    Ok(())
 }
 
-// Helper function to check if a signer is the authorized admin
-pub fn check_authorized_admin(signer: &Pubkey) -> Result<()> {
+// Maximum number of distinct keys that can hold roles at the same time
+pub const MAX_ROLE_MEMBERS: usize = 10;
+
+// Helper function to check if a signer holds a role in the registry
+pub fn check_role(signer: &Pubkey, role_registry: &RoleRegistry, role: Role) -> Result<()> {
     require!(
-        *signer == authorized_admin(),
-        crate::errors::RoundError::UnauthorizedUser
+        role_registry.has_role(signer, role),
+        crate::errors::RoleError::MissingRole
+    );
+    Ok(())
+}
+
+// Pause flags stored in VaultData.paused, one per fund-moving instruction
+pub const PAUSE_DEPOSIT: u8 = 1 << 0;
+pub const PAUSE_PARTICIPATE: u8 = 1 << 1;
+pub const PAUSE_REDEEM: u8 = 1 << 2;
+pub const PAUSE_DISTRIBUTE_REWARD: u8 = 1 << 3;
+pub const PAUSE_DISTRIBUTE_ZBTC_REWARD: u8 = 1 << 4;
+pub const PAUSE_ALL: u8 = PAUSE_DEPOSIT
+    | PAUSE_PARTICIPATE
+    | PAUSE_REDEEM
+    | PAUSE_DISTRIBUTE_REWARD
+    | PAUSE_DISTRIBUTE_ZBTC_REWARD;
+
+// Helper function to reject an instruction whose pause flag is set
+pub fn check_not_paused(vault_data: &VaultData, flag: u8) -> Result<()> {
+    require!(
+        vault_data.paused & flag == 0,
+        crate::errors::PausedError::OperationPaused
     );
     Ok(())
 }
@@ -50,6 +140,21 @@ pub fn find_vault_authority_pda() -> (Pubkey, u8) {
     Pubkey::find_program_address(&[b"vault_authority"], &crate::ID)
 }
 
+pub fn find_config_pda() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"config"], &crate::ID)
+}
+
+pub fn find_pending_action_pda(action_id: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[b"pending_action", action_id.to_le_bytes().as_ref()],
+        &crate::ID
+    )
+}
+
+pub fn find_role_registry_pda() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"role_registry"], &crate::ID)
+}
+
 pub fn find_user_data_pda(user: &Pubkey) -> (Pubkey, u8) {
     Pubkey::find_program_address(
         &[b"user_data", user.as_ref()],
@@ -57,6 +162,13 @@ pub fn find_user_data_pda(user: &Pubkey) -> (Pubkey, u8) {
     )
 }
 
+pub fn find_deposit_receipt_pda(user: &Pubkey, deposit_index: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[b"deposit_receipt", user.as_ref(), deposit_index.to_le_bytes().as_ref()],
+        &crate::ID
+    )
+}
+
 pub fn find_vault_data_pda() -> (Pubkey, u8) {
     Pubkey::find_program_address(
         &[b"vault_data"],
@@ -0,0 +1,367 @@
+// Shared redemption math for redeem and redeem_to_sol
+use anchor_lang::prelude::*;
+use pyth_solana_receiver_sdk::price_update::PriceUpdateV2;
+
+use crate::{
+    debug_msg,
+    errors::{LockError, RedeemError},
+    state::{OracleConfig, UserData, VaultData},
+    utils::deposit::get_oracle_inf_per_sol,
+};
+
+// Amounts of a redemption, as computed by apply_redeem
+pub struct RedeemAmounts {
+    pub sol_principal: u64,
+    pub base_inf_amount: u64,
+    pub inf_to_redeem: u64,  // INF owed to the user at the current rate
+    pub initial_exchange_rate: u64,  // INF per SOL at deposit (scaled by 10^6)
+    pub current_exchange_rate: u64,  // INF per SOL from Pyth (scaled by 10^6)
+}
+
+// Helper function to check a redemption of `sol_amount` principal, price the INF it
+// pays out and deduct it from the user's and the vault's principal
+pub fn apply_redeem(
+    oracle: &OracleConfig,
+    user_data: &mut UserData,
+    vault_data: &mut VaultData,
+    price_update_inf: &PriceUpdateV2,
+    price_update_sol: &PriceUpdateV2,
+    sol_amount: u64,
+    vault_inf_balance: u64,
+) -> Result<RedeemAmounts> {
+    // Check if user has any principal to redeem
+    debug_msg!("Checking user principal");
+    require!(
+        user_data.total_sol_deposited > 0,
+        RedeemError::NoPrincipal
+    );
+
+    require!(
+        sol_amount > 0 && sol_amount <= user_data.total_sol_deposited,
+        RedeemError::InvalidRedeemAmount
+    );
+
+    // Locked principal stays in the vault until its lock expires
+    let now = Clock::get()?.unix_timestamp;
+    let locked_sol = user_data.locked_principal(now);
+    let redeemable_sol = user_data.total_sol_deposited
+        .checked_sub(locked_sol)
+        .unwrap();
+    require!(sol_amount <= redeemable_sol, LockError::PrincipalLocked);
+    let sol_principal = sol_amount;
+    debug_msg!("Redeeming {} of {} redeemable principal (locked: {})", sol_principal, redeemable_sol, locked_sol);
+    
+    // Calculate user's initial exchange rate from stored values
+    debug_msg!("Calculating initial exchange rate");
+    let scale_factor: u128 = 1_000_000; // 10^6 scaling for precision
+    let initial_exchange_rate = (user_data.total_inf_equivalent as u128)
+        .checked_mul(scale_factor)
+        .and_then(|x| x.checked_div(user_data.total_sol_deposited as u128))
+        .and_then(|x| u64::try_from(x).ok())
+        .unwrap_or(0);
+    
+    debug_msg!("Initial exchange rate (scaled by 10^6): {}", initial_exchange_rate);
+    
+    // Get current INF/USD and SOL/USD prices from Pyth at a common exponent
+    debug_msg!("Getting INF/USD and SOL/USD prices");
+    let (sol_price, inf_price) = get_oracle_inf_per_sol(
+        oracle,
+        price_update_inf,
+        price_update_sol,
+    )?;
+    debug_msg!("INF/USD price: {}, SOL/USD price: {}", inf_price, sol_price);
+
+    // Calculate the current INF per SOL rate, the same quantity as the deposit rate
+    debug_msg!("Calculating current INF per SOL rate");
+    let current_exchange_rate = sol_price
+        .checked_mul(scale_factor)
+        .and_then(|x| x.checked_div(inf_price))
+        .and_then(|x| u64::try_from(x).ok())
+        .ok_or(RedeemError::InvalidPriceData)?;
+
+    debug_msg!("Current exchange rate (scaled by 10^6): {}", current_exchange_rate);
+    
+    // Calculate INF amount to redeem at the current rate
+    debug_msg!("Calculating INF to redeem at the current rate");
+    // The redeemed principal's share of the INF equivalent, so the remaining
+    // principal keeps the user's initial rate
+    let base_inf_amount = (user_data.total_inf_equivalent as u128)
+        .checked_mul(sol_principal as u128)
+        .and_then(|x| x.checked_div(user_data.total_sol_deposited as u128))
+        .and_then(|x| u64::try_from(x).ok())
+        .ok_or(RedeemError::InvalidPriceData)?;
+
+    // The principal is paid back as the INF it is worth at the current rate. INF
+    // appreciating against SOL lowers the rate, so fewer INF are paid out and the
+    // rest stays in the vault as yield. A rate above the deposit rate (INF losing
+    // value, or swap slippage on deposit) is capped at the deposited INF, so the
+    // vault stays solvent
+    let inf_to_redeem = (sol_principal as u128)
+        .checked_mul(current_exchange_rate as u128)
+        .and_then(|x| x.checked_div(scale_factor))
+        .map(|x| x.min(base_inf_amount as u128) as u64)
+        .ok_or(RedeemError::InvalidPriceData)?;
+
+    debug_msg!("Base INF amount: {}", base_inf_amount);
+    debug_msg!("INF to redeem: {}", inf_to_redeem);
+    
+    // Verify the vault has enough tokens
+    debug_msg!("Verifying vault balance");
+    require!(
+        vault_inf_balance >= inf_to_redeem,
+        RedeemError::InsufficientVaultBalance
+    );
+    
+    // Update user data to reflect the redemption
+    debug_msg!("Updating user principal");
+    user_data.total_sol_deposited = user_data.total_sol_deposited
+        .checked_sub(sol_principal)
+        .unwrap();
+    user_data.total_inf_equivalent = user_data.total_inf_equivalent
+        .checked_sub(base_inf_amount)
+        .unwrap();
+    // Expired locks no longer hold anything back
+    user_data.prune_expired_locks(now);
+    
+    // Update vault data
+    debug_msg!("Updating vault data");
+    vault_data.total_principal_sol = vault_data.total_principal_sol
+        .checked_sub(sol_principal)
+        .unwrap();
+
+    Ok(RedeemAmounts {
+        sol_principal,
+        base_inf_amount,
+        inf_to_redeem,
+        initial_exchange_rate,
+        current_exchange_rate,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        state::LockTranche,
+        utils::deposit::get_inf_sol_value,
+        utils::testing::{install_clock, oracle_config, price_update, INF_FEED_ID, NOW, SOL_FEED_ID},
+    };
+
+    // INF at $200 and SOL at $100, as (price, exponent)
+    const INF_PRICE: (i64, i32) = (20_000, -2);
+    const SOL_PRICE: (i64, i32) = (10_000, -2);
+
+    fn user_data(total_sol_deposited: u64, total_inf_equivalent: u64) -> UserData {
+        UserData {
+            user: Pubkey::default(),
+            total_sol_deposited,
+            total_inf_equivalent,
+            deposit_count: 1,
+            chip_remainder: 0,
+            referrer: None,
+            locks: Vec::new(),
+        }
+    }
+
+    fn vault_data(total_principal_sol: u64) -> VaultData {
+        VaultData {
+            total_principal_sol,
+            current_round: 0,
+            exchange_rate: 0,
+            last_price_update: 0,
+            is_round_active: false,
+            paused: 0,
+        }
+    }
+
+    // Redeems `sol_amount` from the given user with INF and SOL at the given (price, exponent)
+    fn redeem(
+        user: &mut UserData,
+        vault: &mut VaultData,
+        inf_price: (i64, i32),
+        sol_price: (i64, i32),
+        sol_amount: u64,
+    ) -> Result<RedeemAmounts> {
+        install_clock();
+        apply_redeem(
+            &oracle_config(),
+            user,
+            vault,
+            &price_update(INF_FEED_ID, inf_price.0, inf_price.1),
+            &price_update(SOL_FEED_ID, sol_price.0, sol_price.1),
+            sol_amount,
+            u64::MAX,
+        )
+    }
+
+    #[test]
+    fn full_redeem_at_the_deposit_rate() {
+        // Deposited at 0.5 INF per SOL
+        let mut user = user_data(2_000_000_000, 1_000_000_000);
+        let mut vault = vault_data(2_000_000_000);
+        let amounts = redeem(&mut user, &mut vault, INF_PRICE, SOL_PRICE, 2_000_000_000).unwrap();
+        assert_eq!(amounts.initial_exchange_rate, 500_000);
+        assert_eq!(amounts.current_exchange_rate, 500_000);
+        assert_eq!(amounts.base_inf_amount, 1_000_000_000);
+        assert_eq!(amounts.inf_to_redeem, 1_000_000_000);
+        assert_eq!((user.total_sol_deposited, user.total_inf_equivalent), (0, 0));
+        assert_eq!(vault.total_principal_sol, 0);
+    }
+
+    #[test]
+    fn exponent_mismatch_is_normalized() {
+        // INF_PRICE and SOL_PRICE, each also published with exponent -8
+        let mut same = user_data(2_000_000_000, 1_000_000_000);
+        let mut vault = vault_data(2_000_000_000);
+        let expected = redeem(&mut same, &mut vault, INF_PRICE, SOL_PRICE, 1_000_000_000).unwrap();
+        let mut user = user_data(2_000_000_000, 1_000_000_000);
+        let mut vault = vault_data(2_000_000_000);
+        let inf_price = (20_000_000_000, -8);
+        let amounts = redeem(&mut user, &mut vault, inf_price, SOL_PRICE, 1_000_000_000).unwrap();
+        assert_eq!(amounts.current_exchange_rate, expected.current_exchange_rate);
+        assert_eq!(amounts.inf_to_redeem, expected.inf_to_redeem);
+        let mut user = user_data(2_000_000_000, 1_000_000_000);
+        let mut vault = vault_data(2_000_000_000);
+        let sol_price = (10_000_000_000, -8);
+        let amounts = redeem(&mut user, &mut vault, INF_PRICE, sol_price, 1_000_000_000).unwrap();
+        assert_eq!(amounts.current_exchange_rate, expected.current_exchange_rate);
+        assert_eq!(amounts.inf_to_redeem, expected.inf_to_redeem);
+    }
+
+    #[test]
+    fn partial_redeem_is_proportional() {
+        let mut user = user_data(2_000_000_000, 1_000_000_000);
+        let mut vault = vault_data(5_000_000_000);
+        let amounts = redeem(&mut user, &mut vault, INF_PRICE, SOL_PRICE, 500_000_000).unwrap();
+        assert_eq!(amounts.sol_principal, 500_000_000);
+        assert_eq!(amounts.base_inf_amount, 250_000_000);
+        assert_eq!(amounts.inf_to_redeem, 250_000_000);
+        assert_eq!(user.total_sol_deposited, 1_500_000_000);
+        assert_eq!(user.total_inf_equivalent, 750_000_000);
+        assert_eq!(vault.total_principal_sol, 4_500_000_000);
+
+        // The remaining principal keeps the initial rate
+        let amounts = redeem(&mut user, &mut vault, INF_PRICE, SOL_PRICE, 1_500_000_000).unwrap();
+        assert_eq!(amounts.initial_exchange_rate, 500_000);
+        assert_eq!(amounts.base_inf_amount, 750_000_000);
+        assert_eq!(vault.total_principal_sol, 3_000_000_000);
+    }
+
+    #[test]
+    fn partial_redeem_rounds_in_favour_of_the_vault() {
+        // 1 SOL for 2 INF units: a third of it is worth 0.67 INF units, rounded down
+        let mut user = user_data(3, 2);
+        let mut vault = vault_data(3);
+        let amounts = redeem(&mut user, &mut vault, SOL_PRICE, SOL_PRICE, 1).unwrap();
+        assert_eq!(amounts.base_inf_amount, 0);
+        assert_eq!((user.total_sol_deposited, user.total_inf_equivalent), (2, 2));
+
+        // Splitting a redemption never pays out more than redeeming it at once
+        let mut whole = user_data(3_000_000_001, 1_000_000_000);
+        let mut vault = vault_data(3_000_000_001);
+        let all = redeem(&mut whole, &mut vault, (30_000, -2), SOL_PRICE, 3_000_000_001).unwrap();
+        let mut split = user_data(3_000_000_001, 1_000_000_000);
+        let mut vault = vault_data(3_000_000_001);
+        let mut paid = 0;
+        for sol_amount in [1_000_000_000, 1_000_000_000, 1_000_000_001] {
+            paid += redeem(&mut split, &mut vault, (30_000, -2), SOL_PRICE, sol_amount)
+                .unwrap()
+                .inf_to_redeem;
+        }
+        assert!(paid <= all.inf_to_redeem);
+        assert_eq!((split.total_sol_deposited, split.total_inf_equivalent), (0, 0));
+    }
+
+    #[test]
+    fn rejects_invalid_amounts() {
+        let mut user = user_data(2_000_000_000, 1_000_000_000);
+        let mut vault = vault_data(2_000_000_000);
+        assert!(redeem(&mut user, &mut vault, INF_PRICE, SOL_PRICE, 0).is_err());
+        assert!(redeem(&mut user, &mut vault, INF_PRICE, SOL_PRICE, 2_000_000_001).is_err());
+        assert!(redeem(&mut user_data(0, 0), &mut vault, INF_PRICE, SOL_PRICE, 1).is_err());
+        assert_eq!(user.total_sol_deposited, 2_000_000_000);
+    }
+
+    #[test]
+    fn locked_principal_is_held_back_until_expiry() {
+        let mut user = user_data(2_000_000_000, 1_000_000_000);
+        user.locks = vec![
+            LockTranche { amount: 500_000_000, expiry: NOW - 1 },
+            LockTranche { amount: 1_000_000_000, expiry: NOW + 1 },
+        ];
+        let mut vault = vault_data(2_000_000_000);
+        assert!(redeem(&mut user, &mut vault, INF_PRICE, SOL_PRICE, 1_000_000_001).is_err());
+        redeem(&mut user, &mut vault, INF_PRICE, SOL_PRICE, 1_000_000_000).unwrap();
+        assert_eq!(user.locks, vec![LockTranche { amount: 1_000_000_000, expiry: NOW + 1 }]);
+    }
+
+    #[test]
+    fn inf_appreciation_stays_in_the_vault() {
+        // INF rises from $200 to $250: 2 SOL of principal is now worth 0.8 INF
+        let mut user = user_data(2_000_000_000, 1_000_000_000);
+        let mut vault = vault_data(2_000_000_000);
+        let amounts = redeem(&mut user, &mut vault, (25_000, -2), SOL_PRICE, 2_000_000_000).unwrap();
+        assert_eq!(amounts.current_exchange_rate, 400_000);
+        assert_eq!(amounts.inf_to_redeem, 800_000_000);
+        assert_eq!((user.total_sol_deposited, user.total_inf_equivalent), (0, 0));
+    }
+
+    #[test]
+    fn payout_is_capped_at_the_deposited_inf() {
+        // INF falls from $200 to $100: the principal is worth 2 INF, but only 1 was deposited
+        let mut user = user_data(2_000_000_000, 1_000_000_000);
+        let mut vault = vault_data(2_000_000_000);
+        let amounts = redeem(&mut user, &mut vault, SOL_PRICE, SOL_PRICE, 2_000_000_000).unwrap();
+        assert_eq!(amounts.current_exchange_rate, 1_000_000);
+        assert_eq!(amounts.inf_to_redeem, 1_000_000_000);
+
+        // Swap slippage on deposit: the unchanged rate pays back exactly the INF received
+        let mut user = user_data(2_000_000_000, 990_000_000);
+        let mut vault = vault_data(2_000_000_000);
+        let amounts = redeem(&mut user, &mut vault, INF_PRICE, SOL_PRICE, 2_000_000_000).unwrap();
+        assert_eq!(amounts.inf_to_redeem, 990_000_000);
+    }
+    #[test]
+    fn deposit_inf_round_trip_never_gains_inf() {
+        // deposit_inf credits INF at the oracle rate, redeeming it at the same rate
+        // must not pay out more than was deposited
+        install_clock();
+        let prices = [INF_PRICE, (16_523, -2), (14_987_654_321, -8), (1, 0)];
+        for inf_price in prices {
+            for inf_amount in [1_000, 1_234_567_891, 77_777_777_777] {
+                let sol_equivalent = get_inf_sol_value(
+                    &oracle_config(),
+                    &price_update(INF_FEED_ID, inf_price.0, inf_price.1),
+                    &price_update(SOL_FEED_ID, SOL_PRICE.0, SOL_PRICE.1),
+                    inf_amount,
+                    9,
+                )
+                .unwrap();
+                let mut user = user_data(sol_equivalent, inf_amount);
+                let mut vault = vault_data(sol_equivalent);
+                let amounts =
+                    redeem(&mut user, &mut vault, inf_price, SOL_PRICE, sol_equivalent).unwrap();
+                assert!(amounts.inf_to_redeem <= inf_amount);
+            }
+        }
+    }
+
+    #[test]
+    fn inf_value_scales_by_mint_decimals() {
+        // 1 INF at twice the SOL price is 2 SOL, whatever the INF mint's decimals
+        install_clock();
+        let value = |amount, decimals| {
+            get_inf_sol_value(
+                &oracle_config(),
+                &price_update(INF_FEED_ID, INF_PRICE.0, INF_PRICE.1),
+                &price_update(SOL_FEED_ID, SOL_PRICE.0, SOL_PRICE.1),
+                amount,
+                decimals,
+            )
+            .unwrap()
+        };
+        assert_eq!(value(1_000_000_000, 9), 2_000_000_000);
+        assert_eq!(value(1_000_000, 6), 2_000_000_000);
+        assert_eq!(value(100_000_000_000, 11), 2_000_000_000);
+    }
+}
\ No newline at end of file
@@ -0,0 +1,63 @@
+// Fixtures for unit tests of the helpers that read Pyth prices or the clock
+use anchor_lang::{
+    prelude::*,
+    solana_program::{entrypoint::SUCCESS, program_stubs},
+};
+use pyth_solana_receiver_sdk::price_update::{PriceFeedMessage, PriceUpdateV2, VerificationLevel};
+use std::sync::Once;
+
+use crate::state::OracleConfig;
+
+// Unix timestamp returned by Clock::get() once install_clock has run
+pub const NOW: i64 = 1_700_000_000;
+
+pub const INF_FEED_ID: [u8; 32] = [1; 32];
+pub const SOL_FEED_ID: [u8; 32] = [2; 32];
+
+struct ClockStub;
+
+impl program_stubs::SyscallStubs for ClockStub {
+    fn sol_get_clock_sysvar(&self, var_addr: *mut u8) -> u64 {
+        let clock = Clock { unix_timestamp: NOW, ..Clock::default() };
+        unsafe { *(var_addr as *mut Clock) = clock };
+        SUCCESS
+    }
+}
+
+// Helper function to make Clock::get() available outside the runtime
+pub fn install_clock() {
+    static INSTALL: Once = Once::new();
+    INSTALL.call_once(|| {
+        program_stubs::set_syscall_stubs(Box::new(ClockStub));
+    });
+}
+
+pub fn oracle_config() -> OracleConfig {
+    OracleConfig {
+        inf_usd_feed_id: INF_FEED_ID,
+        sol_usd_feed_id: SOL_FEED_ID,
+        inf_max_age: 60,
+        sol_max_age: 60,
+        max_conf_ratio_bps: 100,
+        max_deposit_deviation_bps: 100,
+    }
+}
+
+// A fully verified price update published at NOW
+pub fn price_update(feed_id: [u8; 32], price: i64, exponent: i32) -> PriceUpdateV2 {
+    PriceUpdateV2 {
+        write_authority: Pubkey::default(),
+        verification_level: VerificationLevel::Full,
+        price_message: PriceFeedMessage {
+            feed_id,
+            price,
+            conf: 0,
+            exponent,
+            publish_time: NOW,
+            prev_publish_time: NOW - 1,
+            ema_price: price,
+            ema_conf: 0,
+        },
+        posted_slot: 0,
+    }
+}
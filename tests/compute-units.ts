@@ -0,0 +1,481 @@
+import * as anchor from "@coral-xyz/anchor";
+import { BN, Program } from "@coral-xyz/anchor";
+import {
+  ComputeBudgetProgram,
+  PublicKey,
+  SystemProgram,
+  Transaction,
+  TransactionInstruction,
+} from "@solana/web3.js";
+import {
+  ASSOCIATED_TOKEN_PROGRAM_ID,
+  NATIVE_MINT,
+  TOKEN_PROGRAM_ID,
+  createAssociatedTokenAccountIdempotent,
+  createMint,
+  createWrappedNativeAccount,
+  getAssociatedTokenAddressSync,
+  mintTo,
+} from "@solana/spl-token";
+import { expect } from "chai";
+import * as fs from "fs";
+import * as path from "path";
+import { TradeDotFun } from "../target/types/trade_dot_fun";
+import jupiterTestIdl from "../idls/jupiter_test.json";
+
+// Measures the compute units of deposit_sol, redeem, distribute_reward and
+// distribute_zbtc_reward against the localnet build deployed by `anchor test`,
+// with the jupiter_test program standing in for Jupiter. Each run saves its
+// numbers to target/cu/<debug|release>.json, CU_BUILD naming the build under
+// test, and compares them with the other build's file once both exist.
+// `anchor run compute-units` runs both builds in turn.
+const CU_DIR = path.join(__dirname, "..", "target", "cu");
+const CU_BUILD = process.env.CU_BUILD;
+if (CU_BUILD !== "debug" && CU_BUILD !== "release") {
+  throw new Error(
+    "Set CU_BUILD to debug or release, or use anchor run compute-units"
+  );
+}
+const debugBuild = CU_BUILD === "debug";
+
+// Loaded from tests/fixtures by the test validator: INF at $165, SOL at $150
+const INF_PRICE_ACCOUNT = new PublicKey(
+  "Ceg5oePJv1a6RR541qKeQaTepvERA3i8SvyueX9tT8Sq"
+);
+const SOL_PRICE_ACCOUNT = new PublicKey(
+  "7UVimffxr9ow1uXYxsr4LHAcV58mLzhmwaeKvJ1pjLiE"
+);
+const feedIdFromHex = (hex: string): number[] =>
+  Array.from(Buffer.from(hex, "hex"));
+const oracle = {
+  infUsdFeedId: feedIdFromHex(
+    "f51570985c642c49c2d6e50156390fdba80bb6d5f7fa389d2f012ced4f7d208f"
+  ),
+  solUsdFeedId: feedIdFromHex(
+    "ef0d8b6fda2ceba41da15d4095d1da392a0d2f8ed0c6c7bc0f4cfac8c280b56d"
+  ),
+  // The fixtures never update, so their age is not checked
+  infMaxAge: new BN(100 * 365 * 24 * 60 * 60),
+  solMaxAge: new BN(100 * 365 * 24 * 60 * 60),
+  maxConfRatioBps: 100,
+  maxDepositDeviationBps: 100,
+};
+
+const BPF_LOADER_UPGRADEABLE_PROGRAM_ID = new PublicKey(
+  "BPFLoaderUpgradeab1e11111111111111111111111"
+);
+const HANDLERS = [
+  "deposit_sol",
+  "redeem",
+  "distribute_reward",
+  "distribute_zbtc_reward",
+];
+
+// 1 SOL swapped at the oracle rate of 150 / 165 INF per SOL
+const DEPOSIT_LAMPORTS = 1_000_000_000;
+const DEPOSIT_INF = 909_090_909;
+
+describe("compute units", () => {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+  const program = anchor.workspace.tradeDotFun as Program<TradeDotFun>;
+  // The stand-in is deployed by the test script, outside the workspace
+  const jupiter = new Program(
+    jupiterTestIdl as unknown as anchor.Idl,
+    provider
+  );
+  const connection = provider.connection;
+  const payer = (provider.wallet as anchor.Wallet).payer;
+  // The wallet is admin, round operator, distributor, depositor and winner
+  const user = provider.wallet.publicKey;
+
+  const pda = (...seeds: Buffer[]): PublicKey =>
+    PublicKey.findProgramAddressSync(seeds, program.programId)[0];
+  const roundSeed = (roundNumber: number): Buffer =>
+    new BN(roundNumber).toArrayLike(Buffer, "le", 8);
+  const ata = (mint: PublicKey, owner: PublicKey): PublicKey =>
+    getAssociatedTokenAddressSync(mint, owner, true);
+
+  const vaultAuthority = pda(Buffer.from("vault_authority"));
+  const poolAuthority = PublicKey.findProgramAddressSync(
+    [Buffer.from("pool")],
+    jupiter.programId
+  )[0];
+  let infMint: PublicKey;
+  let chipMint: PublicKey;
+  let zbtcMint: PublicKey;
+
+  const units: Record<string, number> = {};
+
+  // Sends `ix` and records the units it consumed under `name`
+  const measure = async (name: string, ix: TransactionInstruction) => {
+    const tx = new Transaction().add(
+      ComputeBudgetProgram.setComputeUnitLimit({ units: 1_400_000 }),
+      ix
+    );
+    const signature = await provider.sendAndConfirm(tx, [], {
+      commitment: "confirmed",
+    });
+    const { meta } = await connection.getTransaction(signature, {
+      commitment: "confirmed",
+      maxSupportedTransactionVersion: 0,
+    });
+    units[name] = meta.computeUnitsConsumed;
+    console.log(`${name}: ${units[name]} units`);
+  };
+
+  // A stand-in Jupiter route swapping `inAmount` of `source` for `outAmount`
+  // paid into `destination`, as remaining accounts and instruction data
+  const route = async (
+    authority: PublicKey,
+    source: PublicKey,
+    destination: PublicKey,
+    sourceMint: PublicKey,
+    destinationMint: PublicKey,
+    inAmount: number,
+    outAmount: number
+  ) => {
+    const ix = await jupiter.methods
+      .route(new BN(inAmount), new BN(outAmount))
+      .accounts({
+        tokenProgram: TOKEN_PROGRAM_ID,
+        userTransferAuthority: authority,
+        userSourceTokenAccount: source,
+        userDestinationTokenAccount: destination,
+        destinationTokenAccount: null,
+        platformFeeAccount: null,
+        eventAuthority: jupiter.programId,
+        program: jupiter.programId,
+        poolAuthority,
+        poolSourceTokenAccount: ata(sourceMint, poolAuthority),
+        poolDestinationTokenAccount: ata(destinationMint, poolAuthority),
+        sourceMint,
+        destinationMint,
+      } as any)
+      .instruction();
+    return {
+      data: ix.data,
+      // The program signs for the route's authority
+      accounts: ix.keys.map((account) => ({ ...account, isSigner: false })),
+    };
+  };
+
+  const runRound = async (roundNumber: number) => {
+    const round = pda(Buffer.from("round"), roundSeed(roundNumber));
+    const operatorAccounts = {
+      round,
+      vaultData: pda(Buffer.from("vault_data")),
+      authority: user,
+      roleRegistry: pda(Buffer.from("role_registry")),
+    };
+    await program.methods
+      .startRound(new BN(roundNumber))
+      .accounts(operatorAccounts as any)
+      .rpc();
+    await program.methods
+      .participateRound(new BN(roundNumber))
+      .accounts({
+        user,
+        config: pda(Buffer.from("config")),
+        round,
+        vaultData: pda(Buffer.from("vault_data")),
+        rewardMint: chipMint,
+        userRewardTokenAccount: ata(chipMint, user),
+        roundRewardTokenAccount: ata(chipMint, round),
+        withdrawalTicket: pda(Buffer.from("withdrawal_ticket"), user.toBuffer()),
+        rewardMintProgram: TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+      } as any)
+      .rpc();
+    await program.methods
+      .closeRound(new BN(roundNumber))
+      .accounts(operatorAccounts as any)
+      .rpc();
+  };
+
+  const initializeRound = (roundNumber: number) =>
+    program.methods
+      .initializeRound(new BN(roundNumber))
+      .accounts({
+        round: pda(Buffer.from("round"), roundSeed(roundNumber)),
+        authority: user,
+        roleRegistry: pda(Buffer.from("role_registry")),
+        systemProgram: SystemProgram.programId,
+      } as any)
+      .rpc();
+
+  before(async () => {
+    infMint = await createMint(connection, payer, user, null, 9);
+    zbtcMint = await createMint(connection, payer, user, null, 8);
+    chipMint = await createMint(connection, payer, vaultAuthority, null, 0);
+
+    // The test validator loads the program as upgradeable with the wallet
+    // as its upgrade authority
+    await program.methods
+      .initializeConfig(
+        user,
+        new BN(0),
+        { wsol: NATIVE_MINT, inf: infMint, chip: chipMint, zbtc: zbtcMint },
+        oracle
+      )
+      .accounts({
+        config: pda(Buffer.from("config")),
+        authority: user,
+        program: program.programId,
+        programData: PublicKey.findProgramAddressSync(
+          [program.programId.toBuffer()],
+          BPF_LOADER_UPGRADEABLE_PROGRAM_ID
+        )[0],
+        systemProgram: SystemProgram.programId,
+      } as any)
+      .rpc();
+    await program.methods
+      .initializeVaultData()
+      .accounts({
+        vaultData: pda(Buffer.from("vault_data")),
+        authority: user,
+        systemProgram: SystemProgram.programId,
+      } as any)
+      .rpc();
+    await program.methods
+      .initializeRoleRegistry()
+      .accounts({
+        roleRegistry: pda(Buffer.from("role_registry")),
+        config: pda(Buffer.from("config")),
+        authority: user,
+        systemProgram: SystemProgram.programId,
+      } as any)
+      .rpc();
+    for (const role of [{ roundOperator: {} }, { distributor: {} }]) {
+      await program.methods
+        .grantRole(role as any, user)
+        .accounts({
+          roleRegistry: pda(Buffer.from("role_registry")),
+          config: pda(Buffer.from("config")),
+          authority: user,
+        } as any)
+        .rpc();
+    }
+
+    // Vault, user and stand-in pool token accounts
+    for (const [mint, owner] of [
+      [infMint, vaultAuthority],
+      [zbtcMint, vaultAuthority],
+      [infMint, user],
+      [zbtcMint, user],
+      [NATIVE_MINT, poolAuthority],
+      [infMint, poolAuthority],
+      [zbtcMint, poolAuthority],
+    ]) {
+      await createAssociatedTokenAccountIdempotent(
+        connection,
+        payer,
+        mint,
+        owner,
+        {},
+        TOKEN_PROGRAM_ID,
+        ASSOCIATED_TOKEN_PROGRAM_ID,
+        true
+      );
+    }
+    await createWrappedNativeAccount(
+      connection,
+      payer,
+      user,
+      DEPOSIT_LAMPORTS
+    );
+    await mintTo(
+      connection,
+      payer,
+      infMint,
+      ata(infMint, poolAuthority),
+      payer,
+      10 * DEPOSIT_INF
+    );
+    await mintTo(
+      connection,
+      payer,
+      zbtcMint,
+      ata(zbtcMint, poolAuthority),
+      payer,
+      100_000_000
+    );
+
+    await initializeRound(1);
+  });
+
+  it("deposit_sol", async () => {
+    const swap = await route(
+      user,
+      ata(NATIVE_MINT, user),
+      ata(infMint, vaultAuthority),
+      NATIVE_MINT,
+      infMint,
+      DEPOSIT_LAMPORTS,
+      DEPOSIT_INF
+    );
+    const ix = await program.methods
+      .depositSol(
+        new BN(1),
+        swap.data,
+        new BN(DEPOSIT_INF),
+        new BN(DEPOSIT_LAMPORTS),
+        new BN(0)
+      )
+      .accounts({
+        user,
+        config: pda(Buffer.from("config")),
+        inputMint: NATIVE_MINT,
+        inputMintProgram: TOKEN_PROGRAM_ID,
+        outputMint: infMint,
+        outputMintProgram: TOKEN_PROGRAM_ID,
+        userInputTokenAccount: ata(NATIVE_MINT, user),
+        vaultTokenAccount: ata(infMint, vaultAuthority),
+        vaultAuthority,
+        jupiterProgram: jupiter.programId,
+        rewardMint: chipMint,
+        userRewardTokenAccount: ata(chipMint, user),
+        rewardMintProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+        userData: pda(Buffer.from("user_data"), user.toBuffer()),
+        depositReceipt: null,
+        vaultData: pda(Buffer.from("vault_data")),
+        round: pda(Buffer.from("round"), roundSeed(1)),
+        referrerStats: null,
+        referrerRewardTokenAccount: null,
+        priceUpdateInf: INF_PRICE_ACCOUNT,
+        priceUpdateSol: SOL_PRICE_ACCOUNT,
+        priceUpdateLst: null,
+      } as any)
+      .remainingAccounts(swap.accounts)
+      .instruction();
+    await measure("deposit_sol", ix);
+  });
+
+  it("redeem", async () => {
+    // Half the principal, redeemed before round 1 starts
+    const ix = await program.methods
+      .redeem(new BN(DEPOSIT_LAMPORTS / 2))
+      .accounts({
+        user,
+        config: pda(Buffer.from("config")),
+        userData: pda(Buffer.from("user_data"), user.toBuffer()),
+        vaultData: pda(Buffer.from("vault_data")),
+        tokenMint: infMint,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        userTokenAccount: ata(infMint, user),
+        vaultTokenAccount: ata(infMint, vaultAuthority),
+        vaultAuthority,
+        priceUpdateInf: INF_PRICE_ACCOUNT,
+        priceUpdateSol: SOL_PRICE_ACCOUNT,
+      } as any)
+      .instruction();
+    await measure("redeem", ix);
+  });
+
+  it("distribute_reward", async () => {
+    await runRound(1);
+    const round = pda(Buffer.from("round"), roundSeed(1));
+    const ix = await program.methods
+      .distributeReward(new BN(1), [user], [new BN(100)])
+      .accounts({
+        authority: user,
+        roleRegistry: pda(Buffer.from("role_registry")),
+        config: pda(Buffer.from("config")),
+        round,
+        vaultData: pda(Buffer.from("vault_data")),
+        vaultTokenAccount: ata(infMint, vaultAuthority),
+        rewardMint: chipMint,
+        roundRewardTokenAccount: ata(chipMint, round),
+        vaultAuthority,
+        infMint,
+        infMintProgram: TOKEN_PROGRAM_ID,
+        rewardMintProgram: TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        priceUpdateInf: INF_PRICE_ACCOUNT,
+        priceUpdateSol: SOL_PRICE_ACCOUNT,
+      } as any)
+      .remainingAccounts([
+        { pubkey: ata(infMint, user), isSigner: false, isWritable: true },
+      ])
+      .instruction();
+    await measure("distribute_reward", ix);
+  });
+
+  it("distribute_zbtc_reward", async () => {
+    await initializeRound(2);
+    await runRound(2);
+    const round = pda(Buffer.from("round"), roundSeed(2));
+    const swap = await route(
+      vaultAuthority,
+      ata(infMint, vaultAuthority),
+      ata(zbtcMint, vaultAuthority),
+      infMint,
+      zbtcMint,
+      10_000_000,
+      15_000
+    );
+    const ix = await program.methods
+      .distributeZbtcReward(new BN(2), [user], [new BN(100)], swap.data)
+      .accounts({
+        authority: user,
+        roleRegistry: pda(Buffer.from("role_registry")),
+        config: pda(Buffer.from("config")),
+        round,
+        vaultData: pda(Buffer.from("vault_data")),
+        vaultInfTokenAccount: ata(infMint, vaultAuthority),
+        vaultZbtcTokenAccount: ata(zbtcMint, vaultAuthority),
+        rewardMint: chipMint,
+        roundRewardTokenAccount: ata(chipMint, round),
+        vaultAuthority,
+        infMint,
+        infMintProgram: TOKEN_PROGRAM_ID,
+        zbtcMint,
+        zbtcMintProgram: TOKEN_PROGRAM_ID,
+        rewardMintProgram: TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        jupiterProgram: jupiter.programId,
+        priceUpdateInf: INF_PRICE_ACCOUNT,
+        priceUpdateSol: SOL_PRICE_ACCOUNT,
+      } as any)
+      .remainingAccounts([
+        ...swap.accounts,
+        { pubkey: ata(zbtcMint, user), isSigner: false, isWritable: true },
+      ])
+      .instruction();
+    await measure("distribute_zbtc_reward", ix);
+  });
+
+  it("release build saves units over the debug build", function () {
+    const other = path.join(CU_DIR, `${debugBuild ? "release" : "debug"}.json`);
+    if (!fs.existsSync(other)) {
+      console.log(`No ${other} yet, run the other build to compare`);
+      this.skip();
+    }
+    const otherUnits: Record<string, number> = JSON.parse(
+      fs.readFileSync(other, "utf8")
+    );
+    const debug = debugBuild ? units : otherUnits;
+    const release = debugBuild ? otherUnits : units;
+    for (const name of HANDLERS) {
+      console.log(
+        `${name}: debug=${debug[name]} release=${release[name]} saved=${
+          debug[name] - release[name]
+        }`
+      );
+      expect(release[name]).to.be.below(debug[name]);
+    }
+  });
+
+  after(() => {
+    fs.mkdirSync(CU_DIR, { recursive: true });
+    fs.writeFileSync(
+      path.join(CU_DIR, `${debugBuild ? "debug" : "release"}.json`),
+      JSON.stringify(units, null, 2)
+    );
+  });
+});
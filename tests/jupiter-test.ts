@@ -1,12 +1,13 @@
 import * as anchor from "@coral-xyz/anchor";
 import { Program } from "@coral-xyz/anchor";
-import { JupiterTest } from "../target/types/jupiter_test";
+import jupiterTestIdl from "../idls/jupiter_test.json";
 
 describe("jupiter-test", () => {
   // Configure the client to use the local cluster.
   anchor.setProvider(anchor.AnchorProvider.env());
 
-  const program = anchor.workspace.jupiterTest as Program<JupiterTest>;
+  // Deployed by the test script, outside the workspace
+  const program = new Program(jupiterTestIdl as unknown as anchor.Idl);
 
   it("Is initialized!", async () => {
     // Add your test here.
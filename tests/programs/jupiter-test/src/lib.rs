@@ -0,0 +1,106 @@
+// Localnet stand-in for the Jupiter aggregator v6, deployed at the address localnet builds of
+// trade-dot-fun call (see utils/cluster.rs there). `route` has Jupiter's name, so its
+// discriminator matches, and takes Jupiter's leading route accounts. Instead of a route plan
+// it swaps at the caller's quoted amounts against token accounts held by a pool PDA.
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{
+    transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked,
+};
+
+declare_id!("C9reGqiyEn9n19D8LgFy2BLAY2eCbWH1mAdWSgV1LquJ");
+
+#[program]
+pub mod jupiter_test {
+    use super::*;
+
+    pub fn initialize(_ctx: Context<Initialize>) -> Result<()> {
+        Ok(())
+    }
+
+    // Takes `in_amount` of the source into the pool and pays `quoted_out_amount` of the
+    // destination mint from the pool
+    pub fn route(ctx: Context<Route>, in_amount: u64, quoted_out_amount: u64) -> Result<()> {
+        let accounts = &ctx.accounts;
+        transfer_checked(
+            CpiContext::new(
+                accounts.token_program.to_account_info(),
+                TransferChecked {
+                    from: accounts.user_source_token_account.to_account_info(),
+                    mint: accounts.source_mint.to_account_info(),
+                    to: accounts.pool_source_token_account.to_account_info(),
+                    authority: accounts.user_transfer_authority.to_account_info(),
+                },
+            ),
+            in_amount,
+            accounts.source_mint.decimals,
+        )?;
+
+        // Like Jupiter, an explicit destination account overrides the user's
+        let destination = match &accounts.destination_token_account {
+            Some(destination) => destination.to_account_info(),
+            None => accounts.user_destination_token_account.to_account_info(),
+        };
+        let pool_seeds = &[b"pool".as_ref(), &[ctx.bumps.pool_authority]];
+        transfer_checked(
+            CpiContext::new_with_signer(
+                accounts.token_program.to_account_info(),
+                TransferChecked {
+                    from: accounts.pool_destination_token_account.to_account_info(),
+                    mint: accounts.destination_mint.to_account_info(),
+                    to: destination,
+                    authority: accounts.pool_authority.to_account_info(),
+                },
+                &[&pool_seeds[..]],
+            ),
+            quoted_out_amount,
+            accounts.destination_mint.decimals,
+        )?;
+
+        msg!("Swapped {} for {}", in_amount, quoted_out_amount);
+        Ok(())
+    }
+}
+
+#[derive(Accounts)]
+pub struct Initialize {}
+
+// Jupiter's route accounts up to `program`, then the pool
+#[derive(Accounts)]
+pub struct Route<'info> {
+    pub token_program: Interface<'info, TokenInterface>,
+    pub user_transfer_authority: Signer<'info>,
+
+    #[account(mut)]
+    pub user_source_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub user_destination_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub destination_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    /// CHECK: Unused, this program's ID when no fee is taken
+    pub platform_fee_account: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Unused, kept for Jupiter's account layout
+    pub event_authority: UncheckedAccount<'info>,
+
+    /// CHECK: Unused, kept for Jupiter's account layout
+    pub program: UncheckedAccount<'info>,
+
+    /// CHECK: Pool PDA, owns the pool token accounts
+    #[account(
+        seeds = [b"pool"],
+        bump,
+    )]
+    pub pool_authority: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub pool_source_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub pool_destination_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    pub source_mint: InterfaceAccount<'info, Mint>,
+    pub destination_mint: InterfaceAccount<'info, Mint>,
+}
\ No newline at end of file